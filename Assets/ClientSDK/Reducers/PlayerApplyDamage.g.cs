@@ -12,12 +12,12 @@ namespace SpacetimeDB.Types
 {
     public sealed partial class RemoteReducers : RemoteBase
     {
-        public delegate void PlayerApplyDamageHandler(ReducerEventContext ctx, SpacetimeDB.Identity targetIdentity, float damage);
+        public delegate void PlayerApplyDamageHandler(ReducerEventContext ctx, SpacetimeDB.Identity targetIdentity);
         public event PlayerApplyDamageHandler? OnPlayerApplyDamage;
 
-        public void PlayerApplyDamage(SpacetimeDB.Identity targetIdentity, float damage)
+        public void PlayerApplyDamage(SpacetimeDB.Identity targetIdentity)
         {
-            conn.InternalCallReducer(new Reducer.PlayerApplyDamage(targetIdentity, damage), this.SetCallReducerFlags.PlayerApplyDamageFlags);
+            conn.InternalCallReducer(new Reducer.PlayerApplyDamage(targetIdentity), this.SetCallReducerFlags.PlayerApplyDamageFlags);
         }
 
         public bool InvokePlayerApplyDamage(ReducerEventContext ctx, Reducer.PlayerApplyDamage args)
@@ -25,8 +25,7 @@ namespace SpacetimeDB.Types
             if (OnPlayerApplyDamage == null) return false;
             OnPlayerApplyDamage(
                 ctx,
-                args.TargetIdentity,
-                args.Damage
+                args.TargetIdentity
             );
             return true;
         }
@@ -40,16 +39,10 @@ namespace SpacetimeDB.Types
         {
             [DataMember(Name = "target_identity")]
             public SpacetimeDB.Identity TargetIdentity;
-            [DataMember(Name = "damage")]
-            public float Damage;
 
-            public PlayerApplyDamage(
-                SpacetimeDB.Identity TargetIdentity,
-                float Damage
-            )
+            public PlayerApplyDamage(SpacetimeDB.Identity TargetIdentity)
             {
                 this.TargetIdentity = TargetIdentity;
-                this.Damage = Damage;
             }
 
             public PlayerApplyDamage()
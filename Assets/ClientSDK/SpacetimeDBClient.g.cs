@@ -20,12 +20,35 @@ namespace SpacetimeDB.Types
     {
         public RemoteTables(DbConnection conn)
         {
+            AddTable(Admin = new(conn));
+            AddTable(Blueprint = new(conn));
+            AddTable(BuildingActionLog = new(conn));
+            AddTable(BuildingContainer = new(conn));
+            AddTable(BuildingDecaySchedule = new(conn));
+            AddTable(BuildingPieceLock = new(conn));
             AddTable(BuildingPiecePlaced = new(conn));
+            AddTable(BuildingPieceState = new(conn));
+            AddTable(BuildingPieceSupport = new(conn));
             AddTable(BuildingPieceVariant = new(conn));
+            AddTable(BuildingStation = new(conn));
+            AddTable(CraftingQueue = new(conn));
+            AddTable(CraftingSchedule = new(conn));
             AddTable(CreativeCamera = new(conn));
+            AddTable(Equipment = new(conn));
             AddTable(Inventory = new(conn));
             AddTable(Item = new(conn));
+            AddTable(ItemInstance = new(conn));
+            AddTable(LandClaim = new(conn));
+            AddTable(LandClaimPermission = new(conn));
             AddTable(Player = new(conn));
+            AddTable(PlayerAttack = new(conn));
+            AddTable(PlayerMovement = new(conn));
+            AddTable(Recipe = new(conn));
+            AddTable(ResourceNode = new(conn));
+            AddTable(ResourceNodeRespawnSchedule = new(conn));
+            AddTable(Trade = new(conn));
+            AddTable(WorldItem = new(conn));
+            AddTable(WorldItemDespawnSchedule = new(conn));
             AddTable(WorldSpawn = new(conn));
         }
     }
@@ -435,15 +458,47 @@ namespace SpacetimeDB.Types
             var encodedArgs = update.ReducerCall.Args;
             return update.ReducerCall.ReducerName switch
             {
+                "admin_grant" => BSATNHelpers.Decode<Reducer.AdminGrant>(encodedArgs),
+                "admin_revoke" => BSATNHelpers.Decode<Reducer.AdminRevoke>(encodedArgs),
+                "blueprint_delete" => BSATNHelpers.Decode<Reducer.BlueprintDelete>(encodedArgs),
+                "blueprint_place" => BSATNHelpers.Decode<Reducer.BlueprintPlace>(encodedArgs),
+                "blueprint_save" => BSATNHelpers.Decode<Reducer.BlueprintSave>(encodedArgs),
+                "blueprint_set_shared" => BSATNHelpers.Decode<Reducer.BlueprintSetShared>(encodedArgs),
+                "building_container_deposit" => BSATNHelpers.Decode<Reducer.BuildingContainerDeposit>(encodedArgs),
+                "building_container_withdraw" => BSATNHelpers.Decode<Reducer.BuildingContainerWithdraw>(encodedArgs),
+                "building_decay_tick" => BSATNHelpers.Decode<Reducer.BuildingDecayTick>(encodedArgs),
+                "building_piece_apply_damage" => BSATNHelpers.Decode<Reducer.BuildingPieceApplyDamage>(encodedArgs),
+                "building_piece_clear_lock" => BSATNHelpers.Decode<Reducer.BuildingPieceClearLock>(encodedArgs),
+                "building_piece_interact" => BSATNHelpers.Decode<Reducer.BuildingPieceInteract>(encodedArgs),
                 "building_piece_place" => BSATNHelpers.Decode<Reducer.BuildingPiecePlace>(encodedArgs),
                 "building_piece_remove" => BSATNHelpers.Decode<Reducer.BuildingPieceRemove>(encodedArgs),
+                "building_piece_repair" => BSATNHelpers.Decode<Reducer.BuildingPieceRepair>(encodedArgs),
+                "building_piece_set_lock" => BSATNHelpers.Decode<Reducer.BuildingPieceSetLock>(encodedArgs),
+                "building_piece_transform" => BSATNHelpers.Decode<Reducer.BuildingPieceTransform>(encodedArgs),
+                "building_piece_upgrade" => BSATNHelpers.Decode<Reducer.BuildingPieceUpgrade>(encodedArgs),
+                "building_piece_variant_sync" => BSATNHelpers.Decode<Reducer.BuildingPieceVariantSync>(encodedArgs),
+                "building_redo" => BSATNHelpers.Decode<Reducer.BuildingRedo>(encodedArgs),
+                "building_station_deposit" => BSATNHelpers.Decode<Reducer.BuildingStationDeposit>(encodedArgs),
+                "building_station_withdraw" => BSATNHelpers.Decode<Reducer.BuildingStationWithdraw>(encodedArgs),
+                "building_undo" => BSATNHelpers.Decode<Reducer.BuildingUndo>(encodedArgs),
                 "connect" => BSATNHelpers.Decode<Reducer.Connect>(encodedArgs),
+                "craft_cancel" => BSATNHelpers.Decode<Reducer.CraftCancel>(encodedArgs),
+                "craft_start" => BSATNHelpers.Decode<Reducer.CraftStart>(encodedArgs),
+                "crafting_tick" => BSATNHelpers.Decode<Reducer.CraftingTick>(encodedArgs),
                 "creative_camera_move" => BSATNHelpers.Decode<Reducer.CreativeCameraMove>(encodedArgs),
                 "creative_camera_set_enabled" => BSATNHelpers.Decode<Reducer.CreativeCameraSetEnabled>(encodedArgs),
                 "disconnect" => BSATNHelpers.Decode<Reducer.Disconnect>(encodedArgs),
-                "inventory_add_item" => BSATNHelpers.Decode<Reducer.InventoryAddItem>(encodedArgs),
-                "inventory_create" => BSATNHelpers.Decode<Reducer.InventoryCreate>(encodedArgs),
-                "inventory_remove_item" => BSATNHelpers.Decode<Reducer.InventoryRemoveItem>(encodedArgs),
+                "equipment_equip" => BSATNHelpers.Decode<Reducer.EquipmentEquip>(encodedArgs),
+                "equipment_unequip" => BSATNHelpers.Decode<Reducer.EquipmentUnequip>(encodedArgs),
+                "inventory_admin_grant" => BSATNHelpers.Decode<Reducer.InventoryAdminGrant>(encodedArgs),
+                "inventory_drop_item" => BSATNHelpers.Decode<Reducer.InventoryDropItem>(encodedArgs),
+                "inventory_move_slot" => BSATNHelpers.Decode<Reducer.InventoryMoveSlot>(encodedArgs),
+                "item_instance_rename" => BSATNHelpers.Decode<Reducer.ItemInstanceRename>(encodedArgs),
+                "item_instance_repair" => BSATNHelpers.Decode<Reducer.ItemInstanceRepair>(encodedArgs),
+                "item_sync" => BSATNHelpers.Decode<Reducer.ItemSync>(encodedArgs),
+                "land_claim_deposit_upkeep" => BSATNHelpers.Decode<Reducer.LandClaimDepositUpkeep>(encodedArgs),
+                "land_claim_grant" => BSATNHelpers.Decode<Reducer.LandClaimGrant>(encodedArgs),
+                "land_claim_revoke" => BSATNHelpers.Decode<Reducer.LandClaimRevoke>(encodedArgs),
                 "player_apply_damage" => BSATNHelpers.Decode<Reducer.PlayerApplyDamage>(encodedArgs),
                 "player_connected" => BSATNHelpers.Decode<Reducer.PlayerConnected>(encodedArgs),
                 "player_reset_health" => BSATNHelpers.Decode<Reducer.PlayerResetHealth>(encodedArgs),
@@ -451,6 +506,21 @@ namespace SpacetimeDB.Types
                 "player_set_position" => BSATNHelpers.Decode<Reducer.PlayerSetPosition>(encodedArgs),
                 "player_set_rotation" => BSATNHelpers.Decode<Reducer.PlayerSetRotation>(encodedArgs),
                 "player_update" => BSATNHelpers.Decode<Reducer.PlayerUpdate>(encodedArgs),
+                "recipe_sync" => BSATNHelpers.Decode<Reducer.RecipeSync>(encodedArgs),
+                "resource_node_harvest" => BSATNHelpers.Decode<Reducer.ResourceNodeHarvest>(encodedArgs),
+                "resource_node_remove" => BSATNHelpers.Decode<Reducer.ResourceNodeRemove>(encodedArgs),
+                "resource_node_respawn_tick" => BSATNHelpers.Decode<Reducer.ResourceNodeRespawnTick>(encodedArgs),
+                "resource_node_spawn" => BSATNHelpers.Decode<Reducer.ResourceNodeSpawn>(encodedArgs),
+                "trade_accept" => BSATNHelpers.Decode<Reducer.TradeAccept>(encodedArgs),
+                "trade_cancel" => BSATNHelpers.Decode<Reducer.TradeCancel>(encodedArgs),
+                "trade_confirm" => BSATNHelpers.Decode<Reducer.TradeConfirm>(encodedArgs),
+                "trade_lock" => BSATNHelpers.Decode<Reducer.TradeLock>(encodedArgs),
+                "trade_offer_item" => BSATNHelpers.Decode<Reducer.TradeOfferItem>(encodedArgs),
+                "trade_remove_item" => BSATNHelpers.Decode<Reducer.TradeRemoveItem>(encodedArgs),
+                "trade_request" => BSATNHelpers.Decode<Reducer.TradeRequest>(encodedArgs),
+                "trade_unlock" => BSATNHelpers.Decode<Reducer.TradeUnlock>(encodedArgs),
+                "world_item_despawn_tick" => BSATNHelpers.Decode<Reducer.WorldItemDespawnTick>(encodedArgs),
+                "world_item_pickup" => BSATNHelpers.Decode<Reducer.WorldItemPickup>(encodedArgs),
                 "world_spawn_set" => BSATNHelpers.Decode<Reducer.WorldSpawnSet>(encodedArgs),
                 var reducer => throw new ArgumentOutOfRangeException("Reducer", $"Unknown reducer {reducer}")
             };
@@ -473,15 +543,47 @@ namespace SpacetimeDB.Types
             var eventContext = (ReducerEventContext)context;
             return reducer switch
             {
+                Reducer.AdminGrant args => Reducers.InvokeAdminGrant(eventContext, args),
+                Reducer.AdminRevoke args => Reducers.InvokeAdminRevoke(eventContext, args),
+                Reducer.BlueprintDelete args => Reducers.InvokeBlueprintDelete(eventContext, args),
+                Reducer.BlueprintPlace args => Reducers.InvokeBlueprintPlace(eventContext, args),
+                Reducer.BlueprintSave args => Reducers.InvokeBlueprintSave(eventContext, args),
+                Reducer.BlueprintSetShared args => Reducers.InvokeBlueprintSetShared(eventContext, args),
+                Reducer.BuildingContainerDeposit args => Reducers.InvokeBuildingContainerDeposit(eventContext, args),
+                Reducer.BuildingContainerWithdraw args => Reducers.InvokeBuildingContainerWithdraw(eventContext, args),
+                Reducer.BuildingDecayTick args => Reducers.InvokeBuildingDecayTick(eventContext, args),
+                Reducer.BuildingPieceApplyDamage args => Reducers.InvokeBuildingPieceApplyDamage(eventContext, args),
+                Reducer.BuildingPieceClearLock args => Reducers.InvokeBuildingPieceClearLock(eventContext, args),
+                Reducer.BuildingPieceInteract args => Reducers.InvokeBuildingPieceInteract(eventContext, args),
                 Reducer.BuildingPiecePlace args => Reducers.InvokeBuildingPiecePlace(eventContext, args),
                 Reducer.BuildingPieceRemove args => Reducers.InvokeBuildingPieceRemove(eventContext, args),
+                Reducer.BuildingPieceRepair args => Reducers.InvokeBuildingPieceRepair(eventContext, args),
+                Reducer.BuildingPieceSetLock args => Reducers.InvokeBuildingPieceSetLock(eventContext, args),
+                Reducer.BuildingPieceTransform args => Reducers.InvokeBuildingPieceTransform(eventContext, args),
+                Reducer.BuildingPieceUpgrade args => Reducers.InvokeBuildingPieceUpgrade(eventContext, args),
+                Reducer.BuildingPieceVariantSync args => Reducers.InvokeBuildingPieceVariantSync(eventContext, args),
+                Reducer.BuildingRedo args => Reducers.InvokeBuildingRedo(eventContext, args),
+                Reducer.BuildingStationDeposit args => Reducers.InvokeBuildingStationDeposit(eventContext, args),
+                Reducer.BuildingStationWithdraw args => Reducers.InvokeBuildingStationWithdraw(eventContext, args),
+                Reducer.BuildingUndo args => Reducers.InvokeBuildingUndo(eventContext, args),
                 Reducer.Connect args => Reducers.InvokeConnect(eventContext, args),
+                Reducer.CraftCancel args => Reducers.InvokeCraftCancel(eventContext, args),
+                Reducer.CraftStart args => Reducers.InvokeCraftStart(eventContext, args),
+                Reducer.CraftingTick args => Reducers.InvokeCraftingTick(eventContext, args),
                 Reducer.CreativeCameraMove args => Reducers.InvokeCreativeCameraMove(eventContext, args),
                 Reducer.CreativeCameraSetEnabled args => Reducers.InvokeCreativeCameraSetEnabled(eventContext, args),
                 Reducer.Disconnect args => Reducers.InvokeDisconnect(eventContext, args),
-                Reducer.InventoryAddItem args => Reducers.InvokeInventoryAddItem(eventContext, args),
-                Reducer.InventoryCreate args => Reducers.InvokeInventoryCreate(eventContext, args),
-                Reducer.InventoryRemoveItem args => Reducers.InvokeInventoryRemoveItem(eventContext, args),
+                Reducer.EquipmentEquip args => Reducers.InvokeEquipmentEquip(eventContext, args),
+                Reducer.EquipmentUnequip args => Reducers.InvokeEquipmentUnequip(eventContext, args),
+                Reducer.InventoryAdminGrant args => Reducers.InvokeInventoryAdminGrant(eventContext, args),
+                Reducer.InventoryDropItem args => Reducers.InvokeInventoryDropItem(eventContext, args),
+                Reducer.InventoryMoveSlot args => Reducers.InvokeInventoryMoveSlot(eventContext, args),
+                Reducer.ItemInstanceRename args => Reducers.InvokeItemInstanceRename(eventContext, args),
+                Reducer.ItemInstanceRepair args => Reducers.InvokeItemInstanceRepair(eventContext, args),
+                Reducer.ItemSync args => Reducers.InvokeItemSync(eventContext, args),
+                Reducer.LandClaimDepositUpkeep args => Reducers.InvokeLandClaimDepositUpkeep(eventContext, args),
+                Reducer.LandClaimGrant args => Reducers.InvokeLandClaimGrant(eventContext, args),
+                Reducer.LandClaimRevoke args => Reducers.InvokeLandClaimRevoke(eventContext, args),
                 Reducer.PlayerApplyDamage args => Reducers.InvokePlayerApplyDamage(eventContext, args),
                 Reducer.PlayerConnected args => Reducers.InvokePlayerConnected(eventContext, args),
                 Reducer.PlayerResetHealth args => Reducers.InvokePlayerResetHealth(eventContext, args),
@@ -489,6 +591,21 @@ namespace SpacetimeDB.Types
                 Reducer.PlayerSetPosition args => Reducers.InvokePlayerSetPosition(eventContext, args),
                 Reducer.PlayerSetRotation args => Reducers.InvokePlayerSetRotation(eventContext, args),
                 Reducer.PlayerUpdate args => Reducers.InvokePlayerUpdate(eventContext, args),
+                Reducer.RecipeSync args => Reducers.InvokeRecipeSync(eventContext, args),
+                Reducer.ResourceNodeHarvest args => Reducers.InvokeResourceNodeHarvest(eventContext, args),
+                Reducer.ResourceNodeRemove args => Reducers.InvokeResourceNodeRemove(eventContext, args),
+                Reducer.ResourceNodeRespawnTick args => Reducers.InvokeResourceNodeRespawnTick(eventContext, args),
+                Reducer.ResourceNodeSpawn args => Reducers.InvokeResourceNodeSpawn(eventContext, args),
+                Reducer.TradeAccept args => Reducers.InvokeTradeAccept(eventContext, args),
+                Reducer.TradeCancel args => Reducers.InvokeTradeCancel(eventContext, args),
+                Reducer.TradeConfirm args => Reducers.InvokeTradeConfirm(eventContext, args),
+                Reducer.TradeLock args => Reducers.InvokeTradeLock(eventContext, args),
+                Reducer.TradeOfferItem args => Reducers.InvokeTradeOfferItem(eventContext, args),
+                Reducer.TradeRemoveItem args => Reducers.InvokeTradeRemoveItem(eventContext, args),
+                Reducer.TradeRequest args => Reducers.InvokeTradeRequest(eventContext, args),
+                Reducer.TradeUnlock args => Reducers.InvokeTradeUnlock(eventContext, args),
+                Reducer.WorldItemDespawnTick args => Reducers.InvokeWorldItemDespawnTick(eventContext, args),
+                Reducer.WorldItemPickup args => Reducers.InvokeWorldItemPickup(eventContext, args),
                 Reducer.WorldSpawnSet args => Reducers.InvokeWorldSpawnSet(eventContext, args),
                 _ => throw new ArgumentOutOfRangeException("Reducer", $"Unknown reducer {reducer}")
             };
@@ -17,6 +17,15 @@ namespace SpacetimeDB.Types
         {
             protected override string RemoteTableName => "building_piece_placed";
 
+            public sealed class ChunkIndex : BTreeIndexBase<(int ChunkX, int ChunkZ)>
+            {
+                protected override (int ChunkX, int ChunkZ) GetKey(DbBuildingPiecePlaced row) => (row.ChunkX, row.ChunkZ);
+
+                public ChunkIndex(BuildingPiecePlacedHandle table) : base(table) { }
+            }
+
+            public readonly ChunkIndex Chunk;
+
             public sealed class PieceIdUniqueIndex : UniqueIndexBase<uint>
             {
                 protected override uint GetKey(DbBuildingPiecePlaced row) => row.PieceId;
@@ -28,6 +37,7 @@ namespace SpacetimeDB.Types
 
             internal BuildingPiecePlacedHandle(DbConnection conn) : base(conn)
             {
+                Chunk = new(this);
                 PieceId = new(this);
             }
 
@@ -19,24 +19,48 @@ namespace SpacetimeDB.Types
         public SpacetimeDB.Identity Owner;
         [DataMember(Name = "variant_id")]
         public uint VariantId;
+        [DataMember(Name = "tier")]
+        public DbBuildingMaterialTier Tier;
         [DataMember(Name = "position")]
         public DbVector3 Position;
         [DataMember(Name = "rotation")]
         public DbVector3 Rotation;
+        [DataMember(Name = "stability")]
+        public float Stability;
+        [DataMember(Name = "health")]
+        public float Health;
+        [DataMember(Name = "chunk_x")]
+        public int ChunkX;
+        [DataMember(Name = "chunk_z")]
+        public int ChunkZ;
+        [DataMember(Name = "placed_at")]
+        public SpacetimeDB.Timestamp PlacedAt;
 
         public DbBuildingPiecePlaced(
             uint PieceId,
             SpacetimeDB.Identity Owner,
             uint VariantId,
+            DbBuildingMaterialTier Tier,
             DbVector3 Position,
-            DbVector3 Rotation
+            DbVector3 Rotation,
+            float Stability,
+            float Health,
+            int ChunkX,
+            int ChunkZ,
+            SpacetimeDB.Timestamp PlacedAt
         )
         {
             this.PieceId = PieceId;
             this.Owner = Owner;
             this.VariantId = VariantId;
+            this.Tier = Tier;
             this.Position = Position;
             this.Rotation = Rotation;
+            this.Stability = Stability;
+            this.Health = Health;
+            this.ChunkX = ChunkX;
+            this.ChunkZ = ChunkZ;
+            this.PlacedAt = PlacedAt;
         }
 
         public DbBuildingPiecePlaced()
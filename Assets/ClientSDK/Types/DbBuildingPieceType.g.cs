@@ -14,5 +14,10 @@ namespace SpacetimeDB.Types
         Wall,
         Floor,
         Stair,
+        Claim,
+        Storage,
+        Workbench,
+        Forge,
+        Anvil,
     }
 }
@@ -19,30 +19,60 @@ namespace SpacetimeDB.Types
         public DbBuildingPieceType PieceType;
         [DataMember(Name = "variant_name")]
         public string VariantName;
-        [DataMember(Name = "build_cost")]
-        public System.Collections.Generic.List<DbBuildingCost> BuildCost;
-        [DataMember(Name = "max_health")]
-        public float MaxHealth;
+        [DataMember(Name = "tiers")]
+        public System.Collections.Generic.List<DbBuildingPieceTier> Tiers;
+        [DataMember(Name = "destroyed_drop_fraction")]
+        public float DestroyedDropFraction;
+        [DataMember(Name = "decay_per_hour")]
+        public float DecayPerHour;
+        [DataMember(Name = "interactive")]
+        public bool Interactive;
+        [DataMember(Name = "storage_slots")]
+        public uint StorageSlots;
+        [DataMember(Name = "station")]
+        public DbBuildingStationSlots? Station;
+        [DataMember(Name = "footprint")]
+        public System.Collections.Generic.List<DbVector2> Footprint;
+        [DataMember(Name = "height")]
+        public float Height;
+        [DataMember(Name = "sockets")]
+        public System.Collections.Generic.List<DbVector3> Sockets;
 
         public DbBuildingPieceVariant(
             uint VariantId,
             DbBuildingPieceType PieceType,
             string VariantName,
-            System.Collections.Generic.List<DbBuildingCost> BuildCost,
-            float MaxHealth
+            System.Collections.Generic.List<DbBuildingPieceTier> Tiers,
+            float DestroyedDropFraction,
+            float DecayPerHour,
+            bool Interactive,
+            uint StorageSlots,
+            DbBuildingStationSlots? Station,
+            System.Collections.Generic.List<DbVector2> Footprint,
+            float Height,
+            System.Collections.Generic.List<DbVector3> Sockets
         )
         {
             this.VariantId = VariantId;
             this.PieceType = PieceType;
             this.VariantName = VariantName;
-            this.BuildCost = BuildCost;
-            this.MaxHealth = MaxHealth;
+            this.Tiers = Tiers;
+            this.DestroyedDropFraction = DestroyedDropFraction;
+            this.DecayPerHour = DecayPerHour;
+            this.Interactive = Interactive;
+            this.StorageSlots = StorageSlots;
+            this.Station = Station;
+            this.Footprint = Footprint;
+            this.Height = Height;
+            this.Sockets = Sockets;
         }
 
         public DbBuildingPieceVariant()
         {
             this.VariantName = "";
-            this.BuildCost = new();
+            this.Tiers = new();
+            this.Footprint = new();
+            this.Sockets = new();
         }
     }
 }
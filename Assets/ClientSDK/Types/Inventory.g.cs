@@ -17,23 +17,23 @@ namespace SpacetimeDB.Types
         public SpacetimeDB.Identity Identity;
         [DataMember(Name = "size")]
         public uint Size;
-        [DataMember(Name = "items")]
-        public System.Collections.Generic.List<ItemRef> Items;
+        [DataMember(Name = "slots")]
+        public System.Collections.Generic.List<ItemRef?> Slots;
 
         public Inventory(
             SpacetimeDB.Identity Identity,
             uint Size,
-            System.Collections.Generic.List<ItemRef> Items
+            System.Collections.Generic.List<ItemRef?> Slots
         )
         {
             this.Identity = Identity;
             this.Size = Size;
-            this.Items = Items;
+            this.Slots = Slots;
         }
 
         public Inventory()
         {
-            this.Items = new();
+            this.Slots = new();
         }
     }
 }
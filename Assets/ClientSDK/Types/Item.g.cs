@@ -19,26 +19,47 @@ namespace SpacetimeDB.Types
         public string Name;
         [DataMember(Name = "description")]
         public string Description;
+        [DataMember(Name = "category")]
+        public DbItemCategory Category;
+        [DataMember(Name = "rarity")]
+        public DbItemRarity Rarity;
+        [DataMember(Name = "icon_key")]
+        public string IconKey;
         [DataMember(Name = "weight")]
         public float Weight;
+        [DataMember(Name = "max_stack")]
+        public uint MaxStack;
+        [DataMember(Name = "equipment")]
+        public DbItemEquipment? Equipment;
 
         public Item(
             uint Id,
             string Name,
             string Description,
-            float Weight
+            DbItemCategory Category,
+            DbItemRarity Rarity,
+            string IconKey,
+            float Weight,
+            uint MaxStack,
+            DbItemEquipment? Equipment
         )
         {
             this.Id = Id;
             this.Name = Name;
             this.Description = Description;
+            this.Category = Category;
+            this.Rarity = Rarity;
+            this.IconKey = IconKey;
             this.Weight = Weight;
+            this.MaxStack = MaxStack;
+            this.Equipment = Equipment;
         }
 
         public Item()
         {
             this.Name = "";
             this.Description = "";
+            this.IconKey = "";
         }
     }
 }
@@ -17,14 +17,18 @@ namespace SpacetimeDB.Types
         public uint Id;
         [DataMember(Name = "quantity")]
         public uint Quantity;
+        [DataMember(Name = "instance_id")]
+        public ulong? InstanceId;
 
         public ItemRef(
             uint Id,
-            uint Quantity
+            uint Quantity,
+            ulong? InstanceId
         )
         {
             this.Id = Id;
             this.Quantity = Quantity;
+            this.InstanceId = InstanceId;
         }
 
         public ItemRef()
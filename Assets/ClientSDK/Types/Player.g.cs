@@ -31,6 +31,14 @@ namespace SpacetimeDB.Types
         public float Health;
         [DataMember(Name = "max_health")]
         public float MaxHealth;
+        [DataMember(Name = "last_seen")]
+        public SpacetimeDB.Timestamp LastSeen;
+        [DataMember(Name = "carry_weight")]
+        public float CarryWeight;
+        [DataMember(Name = "max_carry_weight")]
+        public float MaxCarryWeight;
+        [DataMember(Name = "encumbrance")]
+        public DbEncumbrance Encumbrance;
 
         public Player(
             SpacetimeDB.Identity Identity,
@@ -41,7 +49,11 @@ namespace SpacetimeDB.Types
             DbVector2 LookDirection,
             DbAnimationState AnimationState,
             float Health,
-            float MaxHealth
+            float MaxHealth,
+            SpacetimeDB.Timestamp LastSeen,
+            float CarryWeight,
+            float MaxCarryWeight,
+            DbEncumbrance Encumbrance
         )
         {
             this.Identity = Identity;
@@ -53,6 +65,10 @@ namespace SpacetimeDB.Types
             this.AnimationState = AnimationState;
             this.Health = Health;
             this.MaxHealth = MaxHealth;
+            this.LastSeen = LastSeen;
+            this.CarryWeight = CarryWeight;
+            this.MaxCarryWeight = MaxCarryWeight;
+            this.Encumbrance = Encumbrance;
         }
 
         public Player()
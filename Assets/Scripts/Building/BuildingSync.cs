@@ -117,8 +117,11 @@ public class BuildingSync : MonoBehaviour
         var inventory = SpacetimeManager.Conn.Db.Inventory.Identity.Find(SpacetimeManager.LocalIdentity);
         var variant = SpacetimeManager.Conn.Db.BuildingPieceVariant.VariantId.Find(variantId);
 
-        return variant.BuildCost.All(cost =>
-            inventory.Items.Any(item => item.Id == cost.ItemId && item.Quantity >= cost.Quantity)
+        // New pieces are placed at the lowest tier, stacks of the same item add up
+        return variant.Tiers[0].BuildCost.All(cost =>
+            inventory.Slots
+                .Where(item => item != null && item.Id == cost.ItemId)
+                .Sum(item => (long)item.Quantity) >= cost.Quantity
         );
     }
     public static void BuildingPieceRemove(uint pieceId)
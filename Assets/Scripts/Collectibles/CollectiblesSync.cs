@@ -0,0 +1,126 @@
+using UnityEngine;
+using SpacetimeDB.Types;
+using System.Collections.Generic;
+
+public class CollectiblesSync : MonoBehaviour
+{
+    [SerializeField] private CollectiblesDatabase collectiblesDatabase;
+    private Dictionary<uint, ItemPickup> spawnedNodes = new Dictionary<uint, ItemPickup>();
+    private Dictionary<ulong, ItemPickup> spawnedWorldItems = new Dictionary<ulong, ItemPickup>();
+
+    private void OnEnable()
+    {
+        // Subscribe to SpacetimeDB connection events
+        SpacetimeManager.OnConnected += HandleConnected;
+
+        // Subscribe to resource node and world item table events if already connected
+        if (SpacetimeManager.Conn != null)
+        {
+            RegisterTableEvents();
+        }
+    }
+
+    private void OnDisable()
+    {
+        // Unsubscribe from SpacetimeDB connection events
+        SpacetimeManager.OnConnected -= HandleConnected;
+
+        if (SpacetimeManager.Conn != null)
+        {
+            SpacetimeManager.Conn.Db.ResourceNode.OnInsert -= HandleResourceNodeInserted;
+            SpacetimeManager.Conn.Db.ResourceNode.OnUpdate -= HandleResourceNodeUpdated;
+            SpacetimeManager.Conn.Db.ResourceNode.OnDelete -= HandleResourceNodeDeleted;
+            SpacetimeManager.Conn.Db.WorldItem.OnInsert -= HandleWorldItemInserted;
+            SpacetimeManager.Conn.Db.WorldItem.OnDelete -= HandleWorldItemDeleted;
+        }
+    }
+
+    private void HandleConnected()
+    {
+        // Subscribe to table events now that we're connected
+        RegisterTableEvents();
+
+        // Add subscriptions for everything that can be picked up
+        SpacetimeManager.Instance.AddSubscription("select * from resource_node");
+        SpacetimeManager.Instance.AddSubscription("select * from world_item");
+    }
+
+    private void RegisterTableEvents()
+    {
+        SpacetimeManager.Conn.Db.ResourceNode.OnInsert += HandleResourceNodeInserted;
+        SpacetimeManager.Conn.Db.ResourceNode.OnUpdate += HandleResourceNodeUpdated;
+        SpacetimeManager.Conn.Db.ResourceNode.OnDelete += HandleResourceNodeDeleted;
+        SpacetimeManager.Conn.Db.WorldItem.OnInsert += HandleWorldItemInserted;
+        SpacetimeManager.Conn.Db.WorldItem.OnDelete += HandleWorldItemDeleted;
+    }
+
+    private ItemPickup SpawnPickup(uint itemId, uint quantity, DbVector3 position)
+    {
+        var prefab = collectiblesDatabase.GetCollectibleById(itemId);
+        if (prefab == null)
+        {
+            Debug.LogError($"Failed to find collectible prefab for item ID: {itemId}");
+            return null;
+        }
+
+        var pickup = Instantiate(prefab, new Vector3(position.X, position.Y, position.Z), Quaternion.identity);
+        pickup.quantity = quantity;
+        return pickup;
+    }
+
+    private void HandleResourceNodeInserted(EventContext context, DbResourceNode node)
+    {
+        // Don't spawn if we already have this node
+        if (spawnedNodes.ContainsKey(node.NodeId))
+            return;
+
+        var pickup = SpawnPickup(node.ItemId, node.YieldPerHarvest, node.Position);
+        if (pickup != null)
+        {
+            pickup.resourceNodeId = node.NodeId;
+            pickup.gameObject.SetActive(node.Remaining > 0);
+            spawnedNodes[node.NodeId] = pickup;
+        }
+    }
+
+    private void HandleResourceNodeUpdated(EventContext context, DbResourceNode oldData, DbResourceNode newData)
+    {
+        // Depleted nodes are hidden until they respawn
+        if (spawnedNodes.TryGetValue(newData.NodeId, out ItemPickup pickup))
+        {
+            pickup.gameObject.SetActive(newData.Remaining > 0);
+        }
+    }
+
+    private void HandleResourceNodeDeleted(EventContext context, DbResourceNode node)
+    {
+        if (spawnedNodes.TryGetValue(node.NodeId, out ItemPickup pickup))
+        {
+            Destroy(pickup.gameObject);
+            spawnedNodes.Remove(node.NodeId);
+        }
+    }
+
+    private void HandleWorldItemInserted(EventContext context, DbWorldItem worldItem)
+    {
+        // Don't spawn if we already have this item
+        if (spawnedWorldItems.ContainsKey(worldItem.WorldItemId))
+            return;
+
+        var pickup = SpawnPickup(worldItem.ItemId, worldItem.Quantity, worldItem.Position);
+        if (pickup != null)
+        {
+            pickup.worldItemId = worldItem.WorldItemId;
+            spawnedWorldItems[worldItem.WorldItemId] = pickup;
+        }
+    }
+
+    private void HandleWorldItemDeleted(EventContext context, DbWorldItem worldItem)
+    {
+        if (spawnedWorldItems.TryGetValue(worldItem.WorldItemId, out ItemPickup pickup))
+        {
+            Destroy(pickup.gameObject);
+            spawnedWorldItems.Remove(worldItem.WorldItemId);
+        }
+    }
+}
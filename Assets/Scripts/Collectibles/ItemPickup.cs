@@ -11,6 +11,10 @@ public class ItemPickup : MonoBehaviour
     public float itemWeight = 1f;
     public uint quantity = 1;
 
+    // Set by CollectiblesSync for pickups that stand for a server row
+    public uint? resourceNodeId;
+    public ulong? worldItemId;
+
     private PlayerInputActions inputActions;
     private bool playerInRange = false;
 
@@ -68,11 +72,19 @@ public class ItemPickup : MonoBehaviour
 
     private void PickupItem()
     {
-        // Send to server
-        SpacetimeManager.Conn.Reducers.InventoryAddItem(itemId, quantity);
-
-        // Destroy the pickup object
-        // Destroy(gameObject);
+        // Send to server, CollectiblesSync removes the object once the server confirms
+        if (worldItemId.HasValue)
+        {
+            SpacetimeManager.Conn.Reducers.WorldItemPickup(worldItemId.Value);
+        }
+        else if (resourceNodeId.HasValue)
+        {
+            SpacetimeManager.Conn.Reducers.ResourceNodeHarvest(resourceNodeId.Value);
+        }
+        else
+        {
+            Debug.LogWarning($"{itemName} isn't known to the server and can't be picked up");
+        }
     }
 
     private void ShowPickupPrompt()
@@ -46,14 +46,14 @@ public class InventorySync : MonoBehaviour
     private void InvokeInventoryChanged(PlayerEntity playerEntity)
     {
         var inventory = GetInventory(playerEntity);
-        OnInventoryChanged?.Invoke(playerEntity.ownerIdentity, inventory.Items);
+        OnInventoryChanged?.Invoke(playerEntity.ownerIdentity, inventory.Slots);
     }
 
     private void HandleInventoryInserted(EventContext context, Inventory inventory)
     {
         if (PlayerSync.playerObjects.TryGetValue(inventory.Identity, out PlayerEntity playerEntity))
         {
-            playerEntity.inventory = inventory.Items;
+            playerEntity.inventory = inventory.Slots;
 
             InvokeInventoryChanged(playerEntity);
         }
@@ -63,22 +63,22 @@ public class InventorySync : MonoBehaviour
     {
         if (PlayerSync.playerObjects.TryGetValue(newData.Identity, out PlayerEntity playerEntity))
         {
-            playerEntity.inventory = newData.Items;
+            playerEntity.inventory = newData.Slots;
 
             InvokeInventoryChanged(playerEntity);
         }
     }
 
-    public void AddItem(PlayerEntity playerEntity, uint itemId, uint quantity = 1)
+    public void MoveSlot(PlayerEntity playerEntity, uint from, uint to)
     {
-        SpacetimeManager.Conn.Reducers.InventoryAddItem(itemId, quantity);
+        SpacetimeManager.Conn.Reducers.InventoryMoveSlot(from, to);
 
         InvokeInventoryChanged(playerEntity);
     }
 
-    public void RemoveItem(PlayerEntity playerEntity, uint itemId, uint quantity = 1)
+    public void DropItem(PlayerEntity playerEntity, ItemRef item, uint quantity = 1)
     {
-        SpacetimeManager.Conn.Reducers.InventoryRemoveItem(itemId, quantity);
+        SpacetimeManager.Conn.Reducers.InventoryDropItem(item.Id, item.InstanceId, quantity);
 
         InvokeInventoryChanged(playerEntity);
     }
@@ -94,6 +94,6 @@ public class InventorySync : MonoBehaviour
     {
         var inventory = GetInventory(playerEntity);
 
-        return inventory.Items.Find(i => i.Id == itemId);
+        return inventory.Slots.Find(i => i != null && i.Id == itemId);
     }
 }
\ No newline at end of file
@@ -238,7 +238,8 @@ public class PlayerEntity : Entity
     {
         if (IsLocalPlayer() && target is PlayerEntity playerTarget)
         {
-            SpacetimeManager.Conn.Reducers.PlayerApplyDamage(playerTarget.ownerIdentity, damage);
+            // The server works out the damage from what is equipped
+            SpacetimeManager.Conn.Reducers.PlayerApplyDamage(playerTarget.ownerIdentity);
             return;
         }
 
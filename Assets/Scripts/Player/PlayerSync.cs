@@ -73,7 +73,7 @@ public class PlayerSync : MonoBehaviour
         playerEntity.ownerIdentity = playerData.Identity;
 
         // Set the inventory
-        playerEntity.inventory = InventorySync.GetInventory(playerEntity).Items;
+        playerEntity.inventory = InventorySync.GetInventory(playerEntity).Slots;
 
         // Configure the player object based on whether it's the local player
         playerEntity.Configure(playerData, playerCamera, playerHealthDisplay);
@@ -6,14 +6,29 @@ mod modules;
 mod types;
 
 // Local module imports
+use modules::admin::admin_init;
+use modules::building_decay::building_decay_init;
 use modules::building_piece_variant::building_piece_variant_init;
+use modules::crafting::crafting_init;
+use modules::item::item_init;
 use modules::player::{player, player_set_online_status};
+use modules::recipe::recipe_init;
+use modules::resource_node::resource_node_init;
+use modules::trade::trade_cancel_for;
+use modules::world_item::world_item_init;
 use modules::world_spawn::world_spawn_init;
 
 #[spacetimedb::reducer(init)]
 pub fn init(ctx: &ReducerContext) -> Result<(), String> {
+    admin_init(ctx)?;
     world_spawn_init(ctx)?;
+    item_init(ctx)?;
     building_piece_variant_init(ctx)?;
+    recipe_init(ctx)?;
+    building_decay_init(ctx)?;
+    resource_node_init(ctx)?;
+    crafting_init(ctx)?;
+    world_item_init(ctx)?;
     Ok(())
 }
 
@@ -28,5 +43,6 @@ pub fn connect(ctx: &ReducerContext) -> Result<(), String> {
 #[spacetimedb::reducer(client_disconnected)]
 pub fn disconnect(ctx: &ReducerContext) -> Result<(), String> {
     player_set_online_status(ctx, false)?;
+    trade_cancel_for(ctx, ctx.sender);
     Ok(())
 }
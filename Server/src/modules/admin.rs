@@ -0,0 +1,42 @@
+use spacetimedb::{Identity, ReducerContext, Table};
+
+#[spacetimedb::table(name = admin)]
+pub struct Admin {
+    #[primary_key]
+    pub identity: Identity,
+}
+
+pub fn admin_init(ctx: &ReducerContext) -> Result<(), String> {
+    // Whoever publishes the module is its first admin
+    ctx.db.admin().insert(Admin {
+        identity: ctx.sender,
+    });
+    Ok(())
+}
+
+pub fn admin_require(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.admin().identity().find(ctx.sender).is_some() {
+        Ok(())
+    } else {
+        Err("Only admins can do this".to_string())
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn admin_grant(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    admin_require(ctx)?;
+    if ctx.db.admin().identity().find(identity).is_none() {
+        ctx.db.admin().insert(Admin { identity });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn admin_revoke(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    admin_require(ctx)?;
+    if identity == ctx.sender {
+        return Err("Admins can't revoke themselves".to_string());
+    }
+    ctx.db.admin().identity().delete(identity);
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use crate::modules::building_geometry::rotate_yaw;
+use crate::modules::building_piece_placed::{
+    building_piece_placed, building_piece_spawn, DbBuildingPieceType,
+};
+use crate::modules::building_piece_variant::{
+    building_piece_variant_cost, building_piece_variant_get, building_piece_variant_tier,
+    DbBuildingMaterialTier,
+};
+use crate::modules::inventory::{inventory_can_afford, inventory_consume_many};
+use crate::types::DbVector3;
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table};
+use std::collections::HashSet;
+
+const BLUEPRINT_MAX_PIECES: usize = 256;
+const BLUEPRINT_MAX_NAME_LENGTH: usize = 64;
+
+// Transform relative to the blueprint's root
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct DbBlueprintPiece {
+    pub variant_id: u32,
+    pub tier: DbBuildingMaterialTier,
+    pub position: DbVector3,
+    pub rotation: DbVector3,
+}
+
+#[spacetimedb::table(name = blueprint, public)]
+pub struct DbBlueprint {
+    #[primary_key]
+    #[auto_inc]
+    pub blueprint_id: u32,
+    #[index(btree)]
+    pub owner: Identity,
+    pub name: String,
+    pub shared: bool,
+    pub pieces: Vec<DbBlueprintPiece>,
+}
+
+fn blueprint_get_owned(ctx: &ReducerContext, blueprint_id: u32) -> Result<DbBlueprint, String> {
+    let blueprint = ctx
+        .db
+        .blueprint()
+        .blueprint_id()
+        .find(blueprint_id)
+        .ok_or("Blueprint not found")?;
+
+    if blueprint.owner != ctx.sender {
+        return Err("Only the owner can change their blueprints".to_string());
+    }
+    Ok(blueprint)
+}
+
+#[spacetimedb::reducer]
+pub fn blueprint_save(
+    ctx: &ReducerContext,
+    name: String,
+    piece_ids: Vec<u32>,
+) -> Result<(), String> {
+    if name.is_empty() || name.len() > BLUEPRINT_MAX_NAME_LENGTH {
+        return Err("Invalid blueprint name".to_string());
+    }
+    if piece_ids.is_empty() || piece_ids.len() > BLUEPRINT_MAX_PIECES {
+        return Err("Invalid number of pieces in blueprint".to_string());
+    }
+    if piece_ids.iter().collect::<HashSet<_>>().len() != piece_ids.len() {
+        return Err("Blueprint contains the same piece twice".to_string());
+    }
+
+    // Collect the selection, only the sender's own pieces can be captured
+    let mut selection = Vec::new();
+    for piece_id in &piece_ids {
+        let piece = ctx
+            .db
+            .building_piece_placed()
+            .piece_id()
+            .find(piece_id)
+            .ok_or("Building piece not found")?;
+        if piece.owner != ctx.sender {
+            return Err("Only your own building pieces can be saved".to_string());
+        }
+        let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+        selection.push((variant.piece_type, piece));
+    }
+
+    // Foundations first and then bottom to top, so every piece has
+    // something to attach to when the blueprint is placed again
+    selection.sort_by(|(a_type, a), (b_type, b)| {
+        let a_key = (*a_type != DbBuildingPieceType::Foundation, a.position.y);
+        let b_key = (*b_type != DbBuildingPieceType::Foundation, b.position.y);
+        a_key
+            .partial_cmp(&b_key)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    // Store every transform relative to the first piece
+    let root_position = selection[0].1.position.clone();
+    let root_yaw = selection[0].1.rotation.y;
+    let pieces = selection
+        .into_iter()
+        .map(|(_, piece)| {
+            let (x, z) = rotate_yaw(
+                piece.position.x - root_position.x,
+                piece.position.z - root_position.z,
+                -root_yaw,
+            );
+            DbBlueprintPiece {
+                variant_id: piece.variant_id,
+                tier: piece.tier,
+                position: DbVector3 {
+                    x,
+                    y: piece.position.y - root_position.y,
+                    z,
+                },
+                rotation: DbVector3 {
+                    x: piece.rotation.x,
+                    y: (piece.rotation.y - root_yaw).rem_euclid(360.0),
+                    z: piece.rotation.z,
+                },
+            }
+        })
+        .collect();
+
+    ctx.db.blueprint().insert(DbBlueprint {
+        blueprint_id: 0,
+        owner: ctx.sender,
+        name,
+        shared: false,
+        pieces,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn blueprint_set_shared(
+    ctx: &ReducerContext,
+    blueprint_id: u32,
+    shared: bool,
+) -> Result<(), String> {
+    let mut blueprint = blueprint_get_owned(ctx, blueprint_id)?;
+    blueprint.shared = shared;
+    ctx.db.blueprint().blueprint_id().update(blueprint);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn blueprint_delete(ctx: &ReducerContext, blueprint_id: u32) -> Result<(), String> {
+    blueprint_get_owned(ctx, blueprint_id)?;
+    ctx.db.blueprint().blueprint_id().delete(blueprint_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn blueprint_place(
+    ctx: &ReducerContext,
+    blueprint_id: u32,
+    position: DbVector3,
+    rotation: DbVector3,
+) -> Result<(), String> {
+    let blueprint = ctx
+        .db
+        .blueprint()
+        .blueprint_id()
+        .find(blueprint_id)
+        .ok_or("Blueprint not found")?;
+    if blueprint.owner != ctx.sender && !blueprint.shared {
+        return Err("Blueprint is not shared".to_string());
+    }
+
+    // Add up the cost of every piece
+    let mut variants = Vec::new();
+    let mut total_cost = Vec::new();
+    for piece in &blueprint.pieces {
+        let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+        total_cost.extend(building_piece_variant_cost(building_piece_variant_tier(
+            &variant, piece.tier,
+        )?));
+        variants.push(variant);
+    }
+    inventory_can_afford(ctx, ctx.sender, &total_cost)?;
+
+    // Place every piece, any failure rolls back the whole blueprint
+    for (piece, variant) in blueprint.pieces.iter().zip(&variants) {
+        let (x, z) = rotate_yaw(piece.position.x, piece.position.z, rotation.y);
+        let piece_position = DbVector3 {
+            x: position.x + x,
+            y: position.y + piece.position.y,
+            z: position.z + z,
+        };
+        let piece_rotation = DbVector3 {
+            x: piece.rotation.x,
+            y: (piece.rotation.y + rotation.y).rem_euclid(360.0),
+            z: piece.rotation.z,
+        };
+        building_piece_spawn(ctx, variant, piece.tier, piece_position, piece_rotation)?;
+    }
+
+    inventory_consume_many(ctx, ctx.sender, &total_cost)?;
+    Ok(())
+}
@@ -0,0 +1,225 @@
+use crate::modules::building_piece_placed::{
+    building_piece_build, building_piece_demolish, building_piece_move, building_piece_placed,
+    DbBuildingPiecePlaced,
+};
+use crate::modules::building_piece_variant::{building_piece_variant_get, DbBuildingMaterialTier};
+use crate::types::DbVector3;
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table};
+
+// How many actions each player can step back through
+const BUILDING_ACTION_LOG_LIMIT: usize = 20;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbBuildingActionKind {
+    Place,
+    Remove,
+    Transform,
+}
+
+#[spacetimedb::table(name = building_action_log, public)]
+#[derive(Clone)]
+pub struct DbBuildingAction {
+    #[primary_key]
+    #[auto_inc]
+    pub action_id: u64,
+    #[index(btree)]
+    pub owner: Identity,
+    pub kind: DbBuildingActionKind,
+    pub piece_id: u32,
+    pub variant_id: u32,
+    pub tier: DbBuildingMaterialTier,
+    pub position: DbVector3,
+    pub rotation: DbVector3,
+    // Transform the piece had before a move
+    pub previous_position: Option<DbVector3>,
+    pub previous_rotation: Option<DbVector3>,
+    pub undone: bool,
+}
+
+fn building_action_list(ctx: &ReducerContext) -> Vec<DbBuildingAction> {
+    let mut actions: Vec<DbBuildingAction> = ctx
+        .db
+        .building_action_log()
+        .owner()
+        .filter(ctx.sender)
+        .collect();
+    actions.sort_by_key(|action| action.action_id);
+    actions
+}
+
+pub fn building_action_record(
+    ctx: &ReducerContext,
+    kind: DbBuildingActionKind,
+    piece: &DbBuildingPiecePlaced,
+    previous: Option<(DbVector3, DbVector3)>,
+) -> Result<(), String> {
+    // A new action replaces anything that could still be redone
+    let mut actions = building_action_list(ctx);
+    for action in actions.iter().filter(|action| action.undone) {
+        ctx.db
+            .building_action_log()
+            .action_id()
+            .delete(action.action_id);
+    }
+    actions.retain(|action| !action.undone);
+
+    let (previous_position, previous_rotation) = previous.unzip();
+    ctx.db.building_action_log().insert(DbBuildingAction {
+        action_id: 0,
+        owner: ctx.sender,
+        kind,
+        piece_id: piece.piece_id,
+        variant_id: piece.variant_id,
+        tier: piece.tier,
+        position: piece.position.clone(),
+        rotation: piece.rotation.clone(),
+        previous_position,
+        previous_rotation,
+        undone: false,
+    });
+
+    // Drop the oldest actions once over the limit
+    let overflow = (actions.len() + 1).saturating_sub(BUILDING_ACTION_LOG_LIMIT);
+    for action in actions.iter().take(overflow) {
+        ctx.db
+            .building_action_log()
+            .action_id()
+            .delete(action.action_id);
+    }
+    Ok(())
+}
+
+// Points older actions at a piece that was rebuilt under a new id
+fn building_action_remap(ctx: &ReducerContext, old_piece_id: u32, new_piece_id: u32) {
+    for mut action in building_action_list(ctx) {
+        if action.piece_id == old_piece_id {
+            action.piece_id = new_piece_id;
+            ctx.db.building_action_log().action_id().update(action);
+        }
+    }
+}
+
+fn building_action_piece(ctx: &ReducerContext, piece_id: u32) -> Option<DbBuildingPiecePlaced> {
+    ctx.db.building_piece_placed().piece_id().find(piece_id)
+}
+
+// Builds the piece again at the given transform, charging its cost
+fn building_action_rebuild(
+    ctx: &ReducerContext,
+    action: &DbBuildingAction,
+    position: DbVector3,
+    rotation: DbVector3,
+) -> Result<(), String> {
+    let variant = building_piece_variant_get(ctx, action.variant_id)?;
+    let piece = building_piece_build(ctx, &variant, action.tier, position, rotation)?;
+    building_action_remap(ctx, action.piece_id, piece.piece_id);
+    Ok(())
+}
+
+fn building_action_set_undone(ctx: &ReducerContext, action_id: u64, undone: bool) {
+    // Re-read the action, a rebuild may have changed its piece id
+    if let Some(mut action) = ctx.db.building_action_log().action_id().find(action_id) {
+        action.undone = undone;
+        ctx.db.building_action_log().action_id().update(action);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn building_undo(ctx: &ReducerContext) -> Result<(), String> {
+    for action in building_action_list(ctx).into_iter().rev() {
+        if action.undone {
+            continue;
+        }
+
+        // Pieces destroyed since the action can't be undone, skip past them
+        match action.kind {
+            DbBuildingActionKind::Place => match building_action_piece(ctx, action.piece_id) {
+                Some(piece) => building_piece_demolish(ctx, &piece)?,
+                None => {
+                    ctx.db
+                        .building_action_log()
+                        .action_id()
+                        .delete(action.action_id);
+                    continue;
+                }
+            },
+            DbBuildingActionKind::Remove => {
+                building_action_rebuild(
+                    ctx,
+                    &action,
+                    action.position.clone(),
+                    action.rotation.clone(),
+                )?;
+            }
+            DbBuildingActionKind::Transform => match building_action_piece(ctx, action.piece_id) {
+                Some(piece) => {
+                    let position = action.previous_position.clone().ok_or("Invalid action")?;
+                    let rotation = action.previous_rotation.clone().ok_or("Invalid action")?;
+                    building_piece_move(ctx, piece, position, rotation)?;
+                }
+                None => {
+                    ctx.db
+                        .building_action_log()
+                        .action_id()
+                        .delete(action.action_id);
+                    continue;
+                }
+            },
+        }
+
+        building_action_set_undone(ctx, action.action_id, true);
+        return Ok(());
+    }
+    Err("Nothing to undo".to_string())
+}
+
+#[spacetimedb::reducer]
+pub fn building_redo(ctx: &ReducerContext) -> Result<(), String> {
+    for action in building_action_list(ctx) {
+        if !action.undone {
+            continue;
+        }
+
+        match action.kind {
+            DbBuildingActionKind::Place => {
+                building_action_rebuild(
+                    ctx,
+                    &action,
+                    action.position.clone(),
+                    action.rotation.clone(),
+                )?;
+            }
+            DbBuildingActionKind::Remove => match building_action_piece(ctx, action.piece_id) {
+                Some(piece) => building_piece_demolish(ctx, &piece)?,
+                None => {
+                    ctx.db
+                        .building_action_log()
+                        .action_id()
+                        .delete(action.action_id);
+                    continue;
+                }
+            },
+            DbBuildingActionKind::Transform => match building_action_piece(ctx, action.piece_id) {
+                Some(piece) => {
+                    building_piece_move(
+                        ctx,
+                        piece,
+                        action.position.clone(),
+                        action.rotation.clone(),
+                    )?;
+                }
+                None => {
+                    ctx.db
+                        .building_action_log()
+                        .action_id()
+                        .delete(action.action_id);
+                    continue;
+                }
+            },
+        }
+
+        building_action_set_undone(ctx, action.action_id, false);
+        return Ok(());
+    }
+    Err("Nothing to redo".to_string())
+}
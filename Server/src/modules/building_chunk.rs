@@ -0,0 +1,78 @@
+use crate::modules::building_piece_placed::{building_piece_placed, DbBuildingPiecePlaced};
+use crate::types::DbVector3;
+use spacetimedb::ReducerContext;
+
+// Width of a chunk on the x/z plane, clients subscribe to pieces per chunk
+pub const CHUNK_SIZE: f32 = 32.0;
+
+pub fn building_chunk_of(position: &DbVector3) -> (i32, i32) {
+    (
+        (position.x / CHUNK_SIZE).floor() as i32,
+        (position.z / CHUNK_SIZE).floor() as i32,
+    )
+}
+
+pub fn building_chunk_pieces(
+    ctx: &ReducerContext,
+    chunk_x: i32,
+    chunk_z: i32,
+) -> Vec<DbBuildingPiecePlaced> {
+    ctx.db
+        .building_piece_placed()
+        .chunk()
+        .filter((chunk_x, chunk_z))
+        .collect()
+}
+
+// Returns every piece whose pivot lies within the radius, only visiting
+// the chunks that the radius overlaps
+pub fn building_chunk_pieces_in_radius(
+    ctx: &ReducerContext,
+    center: &DbVector3,
+    radius: f32,
+) -> Vec<DbBuildingPiecePlaced> {
+    building_chunk_pieces_within(ctx, center, radius, |position| {
+        position.distance(center) <= radius
+    })
+}
+
+// Same as above but ignores height, for areas that reach up to the sky
+pub fn building_chunk_pieces_in_column(
+    ctx: &ReducerContext,
+    center: &DbVector3,
+    radius: f32,
+) -> Vec<DbBuildingPiecePlaced> {
+    building_chunk_pieces_within(ctx, center, radius, |position| {
+        position.horizontal_distance(center) <= radius
+    })
+}
+
+fn building_chunk_pieces_within(
+    ctx: &ReducerContext,
+    center: &DbVector3,
+    radius: f32,
+    within: impl Fn(&DbVector3) -> bool,
+) -> Vec<DbBuildingPiecePlaced> {
+    let (min_x, min_z) = building_chunk_of(&DbVector3 {
+        x: center.x - radius,
+        y: center.y,
+        z: center.z - radius,
+    });
+    let (max_x, max_z) = building_chunk_of(&DbVector3 {
+        x: center.x + radius,
+        y: center.y,
+        z: center.z + radius,
+    });
+
+    let mut pieces = Vec::new();
+    for chunk_x in min_x..=max_x {
+        for chunk_z in min_z..=max_z {
+            pieces.extend(
+                building_chunk_pieces(ctx, chunk_x, chunk_z)
+                    .into_iter()
+                    .filter(|piece| within(&piece.position)),
+            );
+        }
+    }
+    pieces
+}
@@ -0,0 +1,108 @@
+use crate::modules::building_piece_placed::DbBuildingPiecePlaced;
+use crate::modules::building_storage::{
+    building_storage_deposit, building_storage_drop, building_storage_require_access,
+    building_storage_return, building_storage_withdraw,
+};
+use crate::modules::inventory::ItemRef;
+use spacetimedb::{Identity, ReducerContext, Table};
+
+// How close a player has to stand to use a container
+const CONTAINER_RANGE: f32 = 4.0;
+
+// Items stored in a placed storage piece
+#[spacetimedb::table(name = building_container, public)]
+pub struct DbBuildingContainer {
+    #[primary_key]
+    pub piece_id: u32,
+    pub size: u32,
+    // Same slot layout as a player inventory
+    pub slots: Vec<Option<ItemRef>>,
+}
+
+pub fn building_container_create(ctx: &ReducerContext, piece_id: u32, size: u32) {
+    ctx.db.building_container().insert(DbBuildingContainer {
+        piece_id,
+        size,
+        slots: vec![None; size as usize],
+    });
+}
+
+// Spills everything in the container onto the ground around the piece
+pub fn building_container_drop(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+    locked_to: Option<Identity>,
+) {
+    if let Some(mut container) = ctx.db.building_container().piece_id().find(piece.piece_id) {
+        building_storage_drop(ctx, [&mut container.slots], piece, locked_to);
+        ctx.db.building_container().piece_id().update(container);
+    }
+}
+
+pub fn building_container_delete(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+) -> Result<(), String> {
+    if let Some(container) = ctx.db.building_container().piece_id().find(piece.piece_id) {
+        building_storage_return(ctx, [&container.slots], piece);
+        ctx.db
+            .building_container()
+            .piece_id()
+            .delete(piece.piece_id);
+    }
+    Ok(())
+}
+
+fn building_container_get(
+    ctx: &ReducerContext,
+    piece_id: u32,
+) -> Result<DbBuildingContainer, String> {
+    let container = ctx
+        .db
+        .building_container()
+        .piece_id()
+        .find(piece_id)
+        .ok_or("Building piece is not a container")?;
+    building_storage_require_access(ctx, piece_id, CONTAINER_RANGE)?;
+    Ok(container)
+}
+
+#[spacetimedb::reducer]
+pub fn building_container_deposit(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    item_id: u32,
+    instance_id: Option<u64>,
+    quantity: u32,
+) -> Result<(), String> {
+    let mut container = building_container_get(ctx, piece_id)?;
+
+    let item = ItemRef {
+        id: item_id,
+        quantity,
+        instance_id,
+    };
+    building_storage_deposit(ctx, &mut container.slots, item, "Container is full")?;
+    ctx.db.building_container().piece_id().update(container);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn building_container_withdraw(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    item_id: u32,
+    instance_id: Option<u64>,
+    quantity: u32,
+) -> Result<(), String> {
+    let mut container = building_container_get(ctx, piece_id)?;
+
+    let item = ItemRef {
+        id: item_id,
+        quantity,
+        instance_id,
+    };
+    building_storage_withdraw(ctx, &mut container.slots, item)?;
+    ctx.db.building_container().piece_id().update(container);
+    Ok(())
+}
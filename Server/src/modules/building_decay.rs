@@ -0,0 +1,131 @@
+use crate::modules::building_chunk::building_chunk_pieces_in_column;
+use crate::modules::building_piece_placed::{
+    building_piece_despawn, building_piece_placed, DbBuildingPiecePlaced,
+};
+use crate::modules::building_piece_variant::{
+    building_piece_variant_get, building_piece_variant_tier,
+};
+use crate::modules::building_stability::building_stability_disconnect;
+use crate::modules::land_claim::{land_claim, land_claim_at, DbLandClaim};
+use crate::modules::player::player;
+use spacetimedb::{ReducerContext, ScheduleAt, Table, TimeDuration};
+use std::collections::{BTreeMap, HashSet};
+use std::time::Duration;
+
+const DECAY_INTERVAL: Duration = Duration::from_secs(60 * 60);
+// How long an owner can be offline before their unclaimed pieces start to decay
+const OFFLINE_GRACE: Duration = Duration::from_secs(3 * 24 * 60 * 60);
+// Portion of a piece's build cost its claim pays every interval
+const UPKEEP_RATE: f32 = 0.01;
+
+#[spacetimedb::table(name = building_decay_schedule, scheduled(building_decay_tick))]
+pub struct BuildingDecaySchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+pub fn building_decay_init(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db
+        .building_decay_schedule()
+        .insert(BuildingDecaySchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from(DECAY_INTERVAL)),
+        });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn building_decay_tick(
+    ctx: &ReducerContext,
+    _schedule: BuildingDecaySchedule,
+) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Building decay can only be run by the scheduler".to_string());
+    }
+
+    // Every claim tries to pay its upkeep first, paid claims protect their pieces
+    let claims: Vec<DbLandClaim> = ctx.db.land_claim().iter().collect();
+    let mut paid_claims = HashSet::new();
+    for claim in claims {
+        let claim_id = claim.claim_id;
+        if building_decay_pay_upkeep(ctx, claim)? {
+            paid_claims.insert(claim_id);
+        }
+    }
+
+    let hours = DECAY_INTERVAL.as_secs_f32() / 3600.0;
+    let pieces: Vec<DbBuildingPiecePlaced> = ctx.db.building_piece_placed().iter().collect();
+    let mut destroyed = Vec::new();
+    for mut piece in pieces {
+        let decaying = match land_claim_at(ctx, &piece.position) {
+            Some(claim) => !paid_claims.contains(&claim.claim_id),
+            None => building_decay_owner_abandoned(ctx, &piece),
+        };
+        if !decaying {
+            continue;
+        }
+
+        let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+        piece.health -= variant.decay_per_hour * hours;
+        if piece.health <= 0.0 {
+            destroyed.push(piece.piece_id);
+        } else {
+            ctx.db.building_piece_placed().piece_id().update(piece);
+        }
+    }
+
+    for piece_id in destroyed {
+        // An earlier collapse may already have taken this piece down
+        if let Some(piece) = ctx.db.building_piece_placed().piece_id().find(piece_id) {
+            log::debug!("Building piece {} decayed", piece_id);
+            building_piece_despawn(ctx, &piece)?;
+            building_stability_disconnect(ctx, piece_id)?;
+        }
+    }
+    Ok(())
+}
+
+fn building_decay_owner_abandoned(ctx: &ReducerContext, piece: &DbBuildingPiecePlaced) -> bool {
+    match ctx.db.player().identity().find(piece.owner) {
+        Some(owner) if owner.online => false,
+        Some(owner) => ctx
+            .timestamp
+            .duration_since(owner.last_seen)
+            .is_some_and(|offline| offline > OFFLINE_GRACE),
+        None => true,
+    }
+}
+
+// Takes this interval's upkeep out of the claim, returns whether it could pay
+fn building_decay_pay_upkeep(ctx: &ReducerContext, mut claim: DbLandClaim) -> Result<bool, String> {
+    let mut upkeep = BTreeMap::new();
+    for piece in building_chunk_pieces_in_column(ctx, &claim.position, claim.radius) {
+        let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+        let stats = building_piece_variant_tier(&variant, piece.tier)?;
+        for cost in &stats.build_cost {
+            *upkeep.entry(cost.item_id).or_insert(0.0) += cost.quantity as f32 * UPKEEP_RATE;
+        }
+    }
+
+    let affordable = upkeep.iter().all(|(item_id, amount)| {
+        claim
+            .upkeep
+            .iter()
+            .any(|item| item.id == *item_id && item.quantity >= amount.ceil() as u32)
+    });
+
+    if affordable {
+        for (item_id, amount) in &upkeep {
+            if let Some(item) = claim.upkeep.iter_mut().find(|item| item.id == *item_id) {
+                item.quantity -= amount.ceil() as u32;
+            }
+        }
+        claim.upkeep.retain(|item| item.quantity > 0);
+    }
+
+    claim.upkeep_paid = affordable;
+    ctx.db.land_claim().claim_id().update(claim);
+    Ok(affordable)
+}
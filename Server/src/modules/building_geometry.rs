@@ -0,0 +1,307 @@
+use crate::modules::building_chunk::building_chunk_pieces_in_radius;
+use crate::modules::building_piece_placed::DbBuildingPieceType;
+use crate::modules::building_piece_variant::{building_piece_variant_get, DbBuildingPieceVariant};
+use crate::modules::building_stability::support_rule;
+use crate::types::DbVector3;
+use spacetimedb::ReducerContext;
+
+// Matches the grid and rotation step used by the Unity BuildingSystem
+const GRID_SIZE: f32 = 1.0;
+const ROTATION_STEP: f32 = 15.0;
+const ROTATION_TOLERANCE: f32 = 0.01;
+// Two sockets closer than this are considered snapped together
+const SOCKET_TOLERANCE: f32 = 0.1;
+// Pieces may sink this far into each other before counting as overlapping,
+// which leaves room for wall thickness at corners and edges
+const OVERLAP_TOLERANCE: f32 = 0.25;
+// Any two pieces that touch have their pivots at most this far apart
+pub const NEIGHBOUR_RADIUS: f32 = 8.0;
+const WORLD_HALF_EXTENT: f32 = 4096.0;
+const WORLD_MIN_HEIGHT: f32 = -256.0;
+const WORLD_MAX_HEIGHT: f32 = 1024.0;
+
+// A piece's footprint, height range and sockets in world space
+pub struct BuildingShape {
+    pub footprint: Vec<(f32, f32)>,
+    pub bottom: f32,
+    pub top: f32,
+    pub sockets: Vec<DbVector3>,
+}
+
+// Rotates a local x/z offset by a yaw in degrees, the same way Unity does
+pub fn rotate_yaw(x: f32, z: f32, yaw: f32) -> (f32, f32) {
+    let (sin, cos) = yaw.to_radians().sin_cos();
+    (x * cos + z * sin, -x * sin + z * cos)
+}
+
+pub fn building_geometry_shape(
+    variant: &DbBuildingPieceVariant,
+    position: &DbVector3,
+    rotation: &DbVector3,
+) -> BuildingShape {
+    let footprint = variant
+        .footprint
+        .iter()
+        .map(|point| {
+            let (x, z) = rotate_yaw(point.x, point.y, rotation.y);
+            (position.x + x, position.z + z)
+        })
+        .collect();
+    let sockets = variant
+        .sockets
+        .iter()
+        .map(|socket| {
+            let (x, z) = rotate_yaw(socket.x, socket.z, rotation.y);
+            DbVector3 {
+                x: position.x + x,
+                y: position.y + socket.y,
+                z: position.z + z,
+            }
+        })
+        .collect();
+
+    BuildingShape {
+        footprint,
+        bottom: position.y,
+        top: position.y + variant.height,
+        sockets,
+    }
+}
+
+fn project(footprint: &[(f32, f32)], axis: (f32, f32)) -> (f32, f32) {
+    footprint
+        .iter()
+        .map(|(x, z)| x * axis.0 + z * axis.1)
+        .fold((f32::MAX, f32::MIN), |(min, max), value| {
+            (min.min(value), max.max(value))
+        })
+}
+
+fn penetrates(a: (f32, f32), b: (f32, f32)) -> bool {
+    a.1.min(b.1) - a.0.max(b.0) > OVERLAP_TOLERANCE
+}
+
+// Separating axis test on the footprints, plus the vertical ranges
+pub fn building_geometry_overlaps(a: &BuildingShape, b: &BuildingShape) -> bool {
+    if !penetrates((a.bottom, a.top), (b.bottom, b.top)) {
+        return false;
+    }
+
+    for footprint in [&a.footprint, &b.footprint] {
+        for i in 0..footprint.len() {
+            let (x1, z1) = footprint[i];
+            let (x2, z2) = footprint[(i + 1) % footprint.len()];
+            let length = ((x2 - x1).powi(2) + (z2 - z1).powi(2)).sqrt();
+            if length == 0.0 {
+                continue;
+            }
+            let axis = (-(z2 - z1) / length, (x2 - x1) / length);
+            if !penetrates(project(&a.footprint, axis), project(&b.footprint, axis)) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+pub fn building_geometry_connected(a: &BuildingShape, b: &BuildingShape) -> bool {
+    a.sockets.iter().any(|socket| {
+        b.sockets
+            .iter()
+            .any(|other| socket.distance(other) <= SOCKET_TOLERANCE)
+    })
+}
+
+fn is_multiple_of(value: f32, step: f32, tolerance: f32) -> bool {
+    let remainder = value.rem_euclid(step);
+    remainder <= tolerance || step - remainder <= tolerance
+}
+
+pub fn building_geometry_validate_transform(
+    position: &DbVector3,
+    rotation: &DbVector3,
+) -> Result<(), String> {
+    let values = [
+        position.x, position.y, position.z, rotation.x, rotation.y, rotation.z,
+    ];
+    if values.iter().any(|value| !value.is_finite()) {
+        return Err("Invalid building piece transform".to_string());
+    }
+
+    if position.x.abs() > WORLD_HALF_EXTENT
+        || position.z.abs() > WORLD_HALF_EXTENT
+        || position.y < WORLD_MIN_HEIGHT
+        || position.y > WORLD_MAX_HEIGHT
+    {
+        return Err("Building piece is outside the world bounds".to_string());
+    }
+
+    // Pieces can only be turned around the vertical axis, in fixed steps
+    if !is_multiple_of(rotation.x, 360.0, ROTATION_TOLERANCE)
+        || !is_multiple_of(rotation.z, 360.0, ROTATION_TOLERANCE)
+        || !is_multiple_of(rotation.y, ROTATION_STEP, ROTATION_TOLERANCE)
+    {
+        return Err("Building piece rotation is not snapped".to_string());
+    }
+    Ok(())
+}
+
+// Checks the piece doesn't intersect anything and is snapped to a legal anchor:
+// grounded pieces sit on the ground grid or against another foundation, everything
+// else has to share a socket with a piece it can attach to. A piece that is being
+// moved passes its own id so it doesn't collide with itself
+pub fn building_geometry_validate_placement(
+    ctx: &ReducerContext,
+    variant: &DbBuildingPieceVariant,
+    position: &DbVector3,
+    rotation: &DbVector3,
+    ignore_piece_id: Option<u32>,
+) -> Result<(), String> {
+    building_geometry_validate_transform(position, rotation)?;
+    let shape = building_geometry_shape(variant, position, rotation);
+
+    let mut anchored = false;
+    for other in building_chunk_pieces_in_radius(ctx, position, NEIGHBOUR_RADIUS) {
+        if Some(other.piece_id) == ignore_piece_id {
+            continue;
+        }
+        let other_variant = building_piece_variant_get(ctx, other.variant_id)?;
+        let other_shape = building_geometry_shape(&other_variant, &other.position, &other.rotation);
+
+        if building_geometry_overlaps(&shape, &other_shape) {
+            return Err("Building piece overlaps an existing piece".to_string());
+        }
+        if building_geometry_connected(&shape, &other_shape)
+            && can_attach(variant.piece_type, other_variant.piece_type)
+        {
+            anchored = true;
+        }
+    }
+
+    if !anchored {
+        let on_grid = support_rule(variant.piece_type).grounded
+            && shape.sockets.iter().any(|socket| {
+                is_multiple_of(socket.x, GRID_SIZE, SOCKET_TOLERANCE)
+                    && is_multiple_of(socket.z, GRID_SIZE, SOCKET_TOLERANCE)
+            });
+        if !on_grid {
+            return Err("Building piece is not snapped to a valid anchor".to_string());
+        }
+    }
+    Ok(())
+}
+
+fn can_attach(piece_type: DbBuildingPieceType, other_type: DbBuildingPieceType) -> bool {
+    (piece_type == DbBuildingPieceType::Foundation && other_type == DbBuildingPieceType::Foundation)
+        || support_rule(piece_type).supported_by.contains(&other_type)
+        || support_rule(other_type).supported_by.contains(&piece_type)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vector(x: f32, y: f32, z: f32) -> DbVector3 {
+        DbVector3 { x, y, z }
+    }
+
+    // An axis aligned box of the given size with its corner at x/z
+    fn cuboid(x: f32, z: f32, size: f32, bottom: f32, top: f32) -> BuildingShape {
+        BuildingShape {
+            footprint: vec![(x, z), (x + size, z), (x + size, z + size), (x, z + size)],
+            bottom,
+            top,
+            sockets: vec![],
+        }
+    }
+
+    #[test]
+    fn overlaps_detects_intersecting_pieces() {
+        let a = cuboid(0.0, 0.0, 2.0, 0.0, 1.0);
+        let b = cuboid(1.0, 1.0, 2.0, 0.0, 1.0);
+        assert!(building_geometry_overlaps(&a, &b));
+        assert!(building_geometry_overlaps(&b, &a));
+    }
+
+    #[test]
+    fn overlaps_ignores_pieces_that_only_touch() {
+        let a = cuboid(0.0, 0.0, 2.0, 0.0, 1.0);
+        let side = cuboid(2.0, 0.0, 2.0, 0.0, 1.0);
+        let above = cuboid(0.0, 0.0, 2.0, 1.0, 2.0);
+        assert!(!building_geometry_overlaps(&a, &side));
+        assert!(!building_geometry_overlaps(&a, &above));
+    }
+
+    #[test]
+    fn overlaps_allows_sinking_in_by_the_tolerance() {
+        let a = cuboid(0.0, 0.0, 2.0, 0.0, 1.0);
+        let b = cuboid(2.0 - OVERLAP_TOLERANCE / 2.0, 0.0, 2.0, 0.0, 1.0);
+        assert!(!building_geometry_overlaps(&a, &b));
+    }
+
+    #[test]
+    fn overlaps_separates_rotated_footprints() {
+        // A diamond whose bounding box overlaps the square but whose edge doesn't
+        let square = cuboid(0.0, 0.0, 2.0, 0.0, 1.0);
+        let diamond = BuildingShape {
+            footprint: vec![(3.5, 1.0), (4.5, 2.0), (3.5, 3.0), (2.5, 2.0)],
+            bottom: 0.0,
+            top: 1.0,
+            sockets: vec![],
+        };
+        assert!(!building_geometry_overlaps(&square, &diamond));
+    }
+
+    #[test]
+    fn is_multiple_of_accepts_values_within_tolerance() {
+        assert!(is_multiple_of(30.0, 15.0, 0.01));
+        assert!(is_multiple_of(29.995, 15.0, 0.01));
+        assert!(is_multiple_of(-45.005, 15.0, 0.01));
+        assert!(is_multiple_of(0.0, 360.0, 0.01));
+        assert!(!is_multiple_of(20.0, 15.0, 0.01));
+        assert!(!is_multiple_of(-7.5, 15.0, 0.01));
+    }
+
+    #[test]
+    fn validate_transform_accepts_snapped_rotations() {
+        let position = vector(10.0, 52.0, -20.0);
+        assert!(building_geometry_validate_transform(&position, &vector(0.0, 45.0, 0.0)).is_ok());
+        assert!(
+            building_geometry_validate_transform(&position, &vector(360.0, -90.0, 0.0)).is_ok()
+        );
+    }
+
+    #[test]
+    fn validate_transform_rejects_unsnapped_rotations() {
+        let position = vector(0.0, 0.0, 0.0);
+        assert!(building_geometry_validate_transform(&position, &vector(0.0, 10.0, 0.0)).is_err());
+        assert!(building_geometry_validate_transform(&position, &vector(90.0, 0.0, 0.0)).is_err());
+    }
+
+    #[test]
+    fn validate_transform_rejects_non_finite_values() {
+        let rotation = vector(0.0, 0.0, 0.0);
+        assert!(
+            building_geometry_validate_transform(&vector(f32::NAN, 0.0, 0.0), &rotation).is_err()
+        );
+        assert!(building_geometry_validate_transform(
+            &vector(0.0, 0.0, 0.0),
+            &vector(0.0, f32::INFINITY, 0.0)
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn validate_transform_rejects_positions_outside_the_world() {
+        let rotation = vector(0.0, 0.0, 0.0);
+        let outside = [
+            vector(WORLD_HALF_EXTENT + 1.0, 0.0, 0.0),
+            vector(0.0, 0.0, -WORLD_HALF_EXTENT - 1.0),
+            vector(0.0, WORLD_MIN_HEIGHT - 1.0, 0.0),
+            vector(0.0, WORLD_MAX_HEIGHT + 1.0, 0.0),
+        ];
+        for position in outside {
+            assert!(building_geometry_validate_transform(&position, &rotation).is_err());
+        }
+    }
+}
@@ -1,27 +1,79 @@
-use crate::modules::building_piece_variant::building_piece_variant_get;
-use crate::modules::inventory::{inventory_add_item, inventory_get_item, inventory_remove_item};
+use crate::modules::building_action_log::{building_action_record, DbBuildingActionKind};
+use crate::modules::building_chunk::building_chunk_of;
+use crate::modules::building_container::{
+    building_container_create, building_container_delete, building_container_drop,
+};
+use crate::modules::building_geometry::building_geometry_validate_placement;
+use crate::modules::building_piece_state::{
+    building_piece_state_create, building_piece_state_delete,
+};
+use crate::modules::building_piece_variant::{
+    building_piece_variant_cost, building_piece_variant_get, building_piece_variant_tier,
+    DbBuildingMaterialTier, DbBuildingPieceVariant,
+};
+use crate::modules::building_stability::{
+    building_stability_compute, building_stability_connect, building_stability_disconnect,
+    building_stability_find_supports, building_stability_reconnect, MIN_STABILITY,
+};
+use crate::modules::building_station::{
+    building_station_create, building_station_delete, building_station_drop,
+};
+use crate::modules::equipment::{equipment_damage, equipment_wear};
+use crate::modules::inventory::{
+    inventory_can_afford, inventory_consume_many, inventory_give_many, inventory_give_or_drop,
+    ItemRef,
+};
+use crate::modules::item::DbEquipmentSlot;
+use crate::modules::land_claim::{
+    land_claim_at, land_claim_check, land_claim_create, land_claim_delete, DbLandClaimAccess,
+};
+use crate::modules::player::{
+    player, player_require_attack_ready, player_require_in_range, ATTACK_RANGE,
+};
 use crate::types::DbVector3;
-use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table};
+use serde::Deserialize;
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+use std::time::Duration;
 
-#[derive(SpacetimeType, Clone, Debug)]
+#[derive(SpacetimeType, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum DbBuildingPieceType {
     Foundation,
     Wall,
     Floor,
     Stair,
+    Claim,
+    Storage,
+    Workbench,
+    Forge,
+    Anvil,
 }
 
-#[spacetimedb::table(name = building_piece_placed, public)]
+#[spacetimedb::table(
+    name = building_piece_placed,
+    public,
+    index(name = chunk, btree(columns = [chunk_x, chunk_z]))
+)]
 pub struct DbBuildingPiecePlaced {
     #[primary_key]
     #[auto_inc]
     pub piece_id: u32,
     pub owner: Identity,
     pub variant_id: u32,
+    pub tier: DbBuildingMaterialTier,
     pub position: DbVector3,
     pub rotation: DbVector3,
+    pub stability: f32,
+    pub health: f32,
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub placed_at: Timestamp,
 }
 
+// Portion of the build cost consumed when repairing a piece from zero health
+const REPAIR_COST_FRACTION: f32 = 0.5;
+// How long after placing a piece its owner can still move it
+const TRANSFORM_GRACE: Duration = Duration::from_secs(5 * 60);
+
 #[spacetimedb::reducer]
 pub fn building_piece_place(
     ctx: &ReducerContext,
@@ -29,54 +81,379 @@ pub fn building_piece_place(
     position: DbVector3,
     rotation: DbVector3,
 ) -> Result<(), String> {
-    // Get the building piece variant to check its cost
+    // New pieces start at the lowest tier
     let variant = building_piece_variant_get(ctx, variant_id)?;
+    let tier = variant.tiers[0].tier;
 
-    // Check if player has all required materials
-    for cost in &variant.build_cost {
-        let inventory = inventory_get_item(ctx, cost.item_id)?;
+    let piece = building_piece_build(ctx, &variant, tier, position, rotation)?;
+    building_action_record(ctx, DbBuildingActionKind::Place, &piece, None)?;
+    Ok(())
+}
 
-        if inventory.quantity < cost.quantity {
-            return Err("Not enough materials to build this piece".to_string());
-        }
-    }
+// Places a piece and charges its build cost from the sender's inventory
+pub fn building_piece_build(
+    ctx: &ReducerContext,
+    variant: &DbBuildingPieceVariant,
+    tier: DbBuildingMaterialTier,
+    position: DbVector3,
+    rotation: DbVector3,
+) -> Result<DbBuildingPiecePlaced, String> {
+    let cost = building_piece_variant_cost(building_piece_variant_tier(variant, tier)?);
+
+    // Check the materials up front so a poor player gets that error first
+    inventory_can_afford(ctx, ctx.sender, &cost)?;
+    let piece = building_piece_spawn(ctx, variant, tier, position, rotation)?;
+    inventory_consume_many(ctx, ctx.sender, &cost)?;
+    Ok(piece)
+}
 
-    // Remove the materials from inventory
-    for cost in &variant.build_cost {
-        inventory_remove_item(ctx, cost.item_id, cost.quantity)?;
+// Validates and places a piece owned by the sender, without charging for it
+pub fn building_piece_spawn(
+    ctx: &ReducerContext,
+    variant: &DbBuildingPieceVariant,
+    tier: DbBuildingMaterialTier,
+    position: DbVector3,
+    rotation: DbVector3,
+) -> Result<DbBuildingPiecePlaced, String> {
+    let stats = building_piece_variant_tier(variant, tier)?;
+
+    // Check that the sender is allowed to build here
+    land_claim_check(ctx, &position, DbLandClaimAccess::Build)?;
+
+    // Check that the piece fits and is snapped to something it can attach to
+    building_geometry_validate_placement(ctx, variant, &position, &rotation, None)?;
+
+    // Check that the piece is connected to something that can hold it up
+    let supports = building_stability_find_supports(ctx, variant, &position, &rotation, None)?;
+    let stability = building_stability_compute(variant.piece_type, &supports);
+    if stability < MIN_STABILITY {
+        return Err("Not enough support to place this piece".to_string());
     }
 
     // Place the building piece
+    let (chunk_x, chunk_z) = building_chunk_of(&position);
     let piece = DbBuildingPiecePlaced {
         piece_id: 0,
         owner: ctx.sender,
-        variant_id,
+        variant_id: variant.variant_id,
+        tier,
         position,
         rotation,
+        stability,
+        health: stats.max_health,
+        chunk_x,
+        chunk_z,
+        placed_at: ctx.timestamp,
     };
-    ctx.db.building_piece_placed().insert(piece);
+    let piece = ctx.db.building_piece_placed().insert(piece);
+    building_stability_connect(ctx, &piece)?;
+
+    if variant.piece_type == DbBuildingPieceType::Claim {
+        land_claim_create(ctx, &piece)?;
+    }
+    if variant.interactive {
+        building_piece_state_create(ctx, piece.piece_id);
+    }
+    if variant.piece_type == DbBuildingPieceType::Storage {
+        building_container_create(ctx, piece.piece_id, variant.storage_slots);
+    }
+    if let Some(slots) = &variant.station {
+        building_station_create(ctx, piece.piece_id, slots);
+    }
+    Ok(piece)
+}
+
+#[spacetimedb::reducer]
+pub fn building_piece_transform(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    position: DbVector3,
+    rotation: DbVector3,
+) -> Result<(), String> {
+    let piece = ctx
+        .db
+        .building_piece_placed()
+        .piece_id()
+        .find(piece_id)
+        .ok_or("Building piece not found")?;
+
+    let previous = (piece.position.clone(), piece.rotation.clone());
+    let piece = building_piece_move(ctx, piece, position, rotation)?;
+    building_action_record(ctx, DbBuildingActionKind::Transform, &piece, Some(previous))?;
+    Ok(())
+}
+
+// Moves a piece in place, running the same checks as placing it. Undo and
+// redo come through here too, so only the owner can move fresh pieces
+pub fn building_piece_move(
+    ctx: &ReducerContext,
+    mut piece: DbBuildingPiecePlaced,
+    position: DbVector3,
+    rotation: DbVector3,
+) -> Result<DbBuildingPiecePlaced, String> {
+    if piece.owner != ctx.sender {
+        return Err("Only the owner can move their building pieces".to_string());
+    }
+    let within_grace = ctx
+        .timestamp
+        .duration_since(piece.placed_at)
+        .is_some_and(|elapsed| elapsed <= TRANSFORM_GRACE);
+    if !within_grace {
+        return Err("Building piece can no longer be moved".to_string());
+    }
+
+    let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+    if variant.piece_type == DbBuildingPieceType::Claim {
+        return Err("Claim pieces can't be moved".to_string());
+    }
+
+    // Ignore the piece's current spot while validating the new one
+    land_claim_check(ctx, &position, DbLandClaimAccess::Build)?;
+    building_geometry_validate_placement(
+        ctx,
+        &variant,
+        &position,
+        &rotation,
+        Some(piece.piece_id),
+    )?;
+    let supports = building_stability_find_supports(
+        ctx,
+        &variant,
+        &position,
+        &rotation,
+        Some(piece.piece_id),
+    )?;
+    let stability = building_stability_compute(variant.piece_type, &supports);
+    if stability < MIN_STABILITY {
+        return Err("Not enough support to place this piece".to_string());
+    }
+
+    let (chunk_x, chunk_z) = building_chunk_of(&position);
+    piece.position = position;
+    piece.rotation = rotation;
+    piece.stability = stability;
+    piece.chunk_x = chunk_x;
+    piece.chunk_z = chunk_z;
+    let piece = ctx.db.building_piece_placed().piece_id().update(piece);
+    building_stability_reconnect(ctx, &piece)?;
+
+    // The supports found above can include pieces resting on this one, which
+    // come down with it once the graph is rebuilt, so the move is rolled back
+    ctx.db
+        .building_piece_placed()
+        .piece_id()
+        .find(piece.piece_id)
+        .ok_or_else(|| "Not enough support to move this piece".to_string())
+}
+
+// Deletes the piece and anything tied to it, callers take care of stability
+pub fn building_piece_despawn(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+) -> Result<(), String> {
+    land_claim_delete(ctx, piece.piece_id)?;
+    building_piece_state_delete(ctx, piece.piece_id);
+    building_container_delete(ctx, piece)?;
+    building_station_delete(ctx, piece)?;
+    ctx.db
+        .building_piece_placed()
+        .piece_id()
+        .delete(piece.piece_id);
     Ok(())
 }
 
 #[spacetimedb::reducer]
 pub fn building_piece_remove(ctx: &ReducerContext, piece_id: u32) -> Result<(), String> {
-    // Only allow removal if the sender is the owner
-    if let Some(piece) = ctx.db.building_piece_placed().piece_id().find(&piece_id) {
-        if piece.owner == ctx.sender {
-            // Get the building piece variant to refund materials
-            let variant = building_piece_variant_get(ctx, piece.variant_id)?;
-
-            // Refund the materials
-            for cost in &variant.build_cost {
-                inventory_add_item(ctx, cost.item_id, cost.quantity)?;
-            }
+    let piece = ctx
+        .db
+        .building_piece_placed()
+        .piece_id()
+        .find(piece_id)
+        .ok_or("Building piece not found")?;
+
+    building_piece_demolish(ctx, &piece)?;
+    building_action_record(ctx, DbBuildingActionKind::Remove, &piece, None)?;
+    Ok(())
+}
+
+// Removes a piece and refunds its build cost to the piece owner's inventory
+pub fn building_piece_demolish(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+) -> Result<(), String> {
+    // Owners can remove their own pieces, claim owners can clear out their claim
+    let claim = land_claim_at(ctx, &piece.position);
+    let is_claim_owner = claim
+        .as_ref()
+        .is_some_and(|claim| claim.owner == ctx.sender);
+    if piece.owner != ctx.sender && !is_claim_owner {
+        return Err("Only the owner can remove their building pieces".to_string());
+    }
+    land_claim_check(ctx, &piece.position, DbLandClaimAccess::Remove)?;
+
+    // Get the building piece variant to refund materials
+    let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+    let stats = building_piece_variant_tier(&variant, piece.tier)?;
 
-            ctx.db.building_piece_placed().piece_id().delete(&piece_id);
+    // Refund the materials to whoever paid for them, dropping what doesn't
+    // fit when a claim owner clears out someone else's piece
+    let refund = building_piece_variant_cost(stats);
+    if piece.owner == ctx.sender {
+        inventory_give_many(ctx, piece.owner, &refund)?;
+    } else {
+        inventory_give_or_drop(ctx, piece.owner, &refund, &piece.position);
+    }
+
+    building_piece_despawn(ctx, piece)?;
+
+    // Collapse anything that was only standing because of this piece
+    building_stability_disconnect(ctx, piece.piece_id)?;
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn building_piece_apply_damage(ctx: &ReducerContext, piece_id: u32) -> Result<(), String> {
+    // Validate that the attacker exists and is online
+    if let Some(attacker) = ctx.db.player().identity().find(ctx.sender) {
+        if !attacker.online {
+            return Err("Attacker is not online".to_string());
+        }
+
+        // Apply the damage of the attacker's weapon to the piece
+        if let Some(mut piece) = ctx.db.building_piece_placed().piece_id().find(piece_id) {
+            player_require_in_range(ctx, &piece.position, ATTACK_RANGE)?;
+            player_require_attack_ready(ctx)?;
+            piece.health -= equipment_damage(ctx, ctx.sender);
+            equipment_wear(ctx, ctx.sender, &[DbEquipmentSlot::MainHand]);
+            if piece.health <= 0.0 {
+                building_piece_destroy(ctx, piece)?;
+            } else {
+                ctx.db.building_piece_placed().piece_id().update(piece);
+            }
             Ok(())
         } else {
-            Err("Only the owner can remove their building pieces".to_string())
+            Err("Building piece not found".to_string())
         }
     } else {
-        Err("Building piece not found".to_string())
+        Err("Attacker not found".to_string())
     }
 }
+
+fn building_piece_destroy(
+    ctx: &ReducerContext,
+    piece: DbBuildingPiecePlaced,
+) -> Result<(), String> {
+    log::debug!("Building piece {} destroyed", piece.piece_id);
+
+    // Drop part of the materials to whoever destroyed the piece
+    let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+    let stats = building_piece_variant_tier(&variant, piece.tier)?;
+    let dropped: Vec<ItemRef> = stats
+        .build_cost
+        .iter()
+        .map(|cost| ItemRef {
+            id: cost.item_id,
+            quantity: (cost.quantity as f32 * variant.destroyed_drop_fraction).floor() as u32,
+            instance_id: None,
+        })
+        .filter(|item| item.quantity > 0)
+        .collect();
+    inventory_give_or_drop(ctx, ctx.sender, &dropped, &piece.position);
+    // Whatever was stored inside spills out, claimable by the attacker first
+    building_container_drop(ctx, &piece, Some(ctx.sender));
+    building_station_drop(ctx, &piece, Some(ctx.sender));
+
+    building_piece_despawn(ctx, &piece)?;
+    building_stability_disconnect(ctx, piece.piece_id)?;
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn building_piece_repair(ctx: &ReducerContext, piece_id: u32) -> Result<(), String> {
+    let mut piece = ctx
+        .db
+        .building_piece_placed()
+        .piece_id()
+        .find(piece_id)
+        .ok_or("Building piece not found")?;
+
+    if piece.owner != ctx.sender {
+        return Err("Only the owner can repair their building pieces".to_string());
+    }
+
+    let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+    let stats = building_piece_variant_tier(&variant, piece.tier)?;
+    if piece.health >= stats.max_health {
+        return Err("Building piece is not damaged".to_string());
+    }
+
+    // The repair cost scales with how much health is missing
+    let missing = (stats.max_health - piece.health) / stats.max_health;
+    let repair_cost: Vec<ItemRef> = stats
+        .build_cost
+        .iter()
+        .map(|cost| ItemRef {
+            id: cost.item_id,
+            quantity: (cost.quantity as f32 * missing * REPAIR_COST_FRACTION).ceil() as u32,
+            instance_id: None,
+        })
+        .collect();
+    inventory_consume_many(ctx, ctx.sender, &repair_cost)?;
+
+    piece.health = stats.max_health;
+    ctx.db.building_piece_placed().piece_id().update(piece);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn building_piece_upgrade(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    tier: DbBuildingMaterialTier,
+) -> Result<(), String> {
+    let mut piece = ctx
+        .db
+        .building_piece_placed()
+        .piece_id()
+        .find(piece_id)
+        .ok_or("Building piece not found")?;
+
+    // Anyone allowed to build in the claim can upgrade, otherwise only the owner
+    land_claim_check(ctx, &piece.position, DbLandClaimAccess::Build)?;
+    if piece.owner != ctx.sender && land_claim_at(ctx, &piece.position).is_none() {
+        return Err("Only the owner can upgrade their building pieces".to_string());
+    }
+
+    if tier <= piece.tier {
+        return Err("Building pieces can only be upgraded to a higher tier".to_string());
+    }
+
+    let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+    let current = building_piece_variant_tier(&variant, piece.tier)?;
+    let upgraded = building_piece_variant_tier(&variant, tier)?;
+
+    // Only the difference to what was already paid is charged
+    let upgrade_cost: Vec<ItemRef> = upgraded
+        .build_cost
+        .iter()
+        .map(|cost| {
+            let paid = current
+                .build_cost
+                .iter()
+                .find(|paid| paid.item_id == cost.item_id)
+                .map_or(0, |paid| paid.quantity);
+            ItemRef {
+                id: cost.item_id,
+                quantity: cost.quantity.saturating_sub(paid),
+                instance_id: None,
+            }
+        })
+        .collect();
+    inventory_consume_many(ctx, ctx.sender, &upgrade_cost)?;
+
+    // Keep the same share of health in the new tier
+    piece.health = piece.health / current.max_health * upgraded.max_health;
+    piece.tier = tier;
+    ctx.db.building_piece_placed().piece_id().update(piece);
+    Ok(())
+}
@@ -0,0 +1,181 @@
+use crate::modules::building_piece_placed::{building_piece_placed, DbBuildingPiecePlaced};
+use crate::modules::inventory::inventory_get_item;
+use crate::modules::item::item;
+use crate::modules::land_claim::{land_claim_at, land_claim_has_access, DbLandClaimAccess};
+use crate::modules::player::player_require_in_range;
+use spacetimedb::{ReducerContext, Table};
+
+// How close a player has to stand to open or lock a piece
+const INTERACT_RANGE: f32 = 4.0;
+const LOCK_CODE_MAX_LENGTH: usize = 16;
+
+// Open state of doors, windows and other interactive pieces, clients
+// subscribe to this to drive their animations
+#[spacetimedb::table(name = building_piece_state, public)]
+pub struct DbBuildingPieceState {
+    #[primary_key]
+    pub piece_id: u32,
+    pub open: bool,
+    pub locked: bool,
+}
+
+// Kept private so the code never reaches other clients
+#[spacetimedb::table(name = building_piece_lock)]
+pub struct DbBuildingPieceLock {
+    #[primary_key]
+    pub piece_id: u32,
+    pub code: Option<String>,
+    pub key_item_id: Option<u32>,
+}
+
+pub fn building_piece_state_create(ctx: &ReducerContext, piece_id: u32) {
+    if ctx
+        .db
+        .building_piece_state()
+        .piece_id()
+        .find(piece_id)
+        .is_none()
+    {
+        ctx.db.building_piece_state().insert(DbBuildingPieceState {
+            piece_id,
+            open: false,
+            locked: false,
+        });
+    }
+}
+
+pub fn building_piece_state_delete(ctx: &ReducerContext, piece_id: u32) {
+    ctx.db.building_piece_lock().piece_id().delete(piece_id);
+    ctx.db.building_piece_state().piece_id().delete(piece_id);
+}
+
+fn building_piece_state_get(
+    ctx: &ReducerContext,
+    piece_id: u32,
+) -> Result<(DbBuildingPiecePlaced, DbBuildingPieceState), String> {
+    let piece = ctx
+        .db
+        .building_piece_placed()
+        .piece_id()
+        .find(piece_id)
+        .ok_or("Building piece not found")?;
+    let state = ctx
+        .db
+        .building_piece_state()
+        .piece_id()
+        .find(piece_id)
+        .ok_or("Building piece can't be interacted with")?;
+    Ok((piece, state))
+}
+
+// Owners and players with access to the surrounding claim always get through
+fn building_piece_state_can_bypass(ctx: &ReducerContext, piece: &DbBuildingPiecePlaced) -> bool {
+    if piece.owner == ctx.sender {
+        return true;
+    }
+    land_claim_at(ctx, &piece.position).is_some_and(|claim| {
+        land_claim_has_access(ctx, &claim, ctx.sender, DbLandClaimAccess::Access)
+    })
+}
+
+fn building_piece_state_can_unlock(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+    code: Option<String>,
+) -> bool {
+    if building_piece_state_can_bypass(ctx, piece) {
+        return true;
+    }
+    let Some(lock) = ctx.db.building_piece_lock().piece_id().find(piece.piece_id) else {
+        return false;
+    };
+
+    let code_matches = lock.code.is_some() && lock.code == code;
+    let has_key = lock.key_item_id.is_some_and(|item_id| {
+        inventory_get_item(ctx, item_id).is_ok_and(|item| item.quantity > 0)
+    });
+    code_matches || has_key
+}
+
+#[spacetimedb::reducer]
+pub fn building_piece_interact(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    code: Option<String>,
+) -> Result<(), String> {
+    let (piece, mut state) = building_piece_state_get(ctx, piece_id)?;
+    player_require_in_range(ctx, &piece.position, INTERACT_RANGE)?;
+
+    if state.locked && !building_piece_state_can_unlock(ctx, &piece, code) {
+        return Err("This piece is locked".to_string());
+    }
+
+    state.open = !state.open;
+    ctx.db.building_piece_state().piece_id().update(state);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn building_piece_set_lock(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    code: Option<String>,
+    key_item_id: Option<u32>,
+) -> Result<(), String> {
+    let (piece, mut state) = building_piece_state_get(ctx, piece_id)?;
+    player_require_in_range(ctx, &piece.position, INTERACT_RANGE)?;
+
+    if !building_piece_state_can_bypass(ctx, &piece) {
+        return Err("Only the owner can lock this piece".to_string());
+    }
+    if code.is_none() && key_item_id.is_none() {
+        return Err("A lock needs a code or a key item".to_string());
+    }
+    if code
+        .as_ref()
+        .is_some_and(|code| code.is_empty() || code.len() > LOCK_CODE_MAX_LENGTH)
+    {
+        return Err("Invalid lock code".to_string());
+    }
+    if let Some(item_id) = key_item_id {
+        if ctx.db.item().id().find(item_id).is_none() {
+            return Err("Key item not found".to_string());
+        }
+    }
+
+    let lock = DbBuildingPieceLock {
+        piece_id,
+        code,
+        key_item_id,
+    };
+    if ctx
+        .db
+        .building_piece_lock()
+        .piece_id()
+        .find(piece_id)
+        .is_some()
+    {
+        ctx.db.building_piece_lock().piece_id().update(lock);
+    } else {
+        ctx.db.building_piece_lock().insert(lock);
+    }
+
+    state.locked = true;
+    ctx.db.building_piece_state().piece_id().update(state);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn building_piece_clear_lock(ctx: &ReducerContext, piece_id: u32) -> Result<(), String> {
+    let (piece, mut state) = building_piece_state_get(ctx, piece_id)?;
+    player_require_in_range(ctx, &piece.position, INTERACT_RANGE)?;
+
+    if !building_piece_state_can_bypass(ctx, &piece) {
+        return Err("Only the owner can unlock this piece".to_string());
+    }
+
+    ctx.db.building_piece_lock().piece_id().delete(piece_id);
+    state.locked = false;
+    ctx.db.building_piece_state().piece_id().update(state);
+    Ok(())
+}
@@ -1,20 +1,69 @@
-use crate::modules::building_piece_placed::DbBuildingPieceType;
+use crate::modules::admin::admin_require;
+use crate::modules::building_piece_placed::{building_piece_placed, DbBuildingPieceType};
+use crate::modules::building_piece_state::{
+    building_piece_state_create, building_piece_state_delete,
+};
+use crate::modules::catalog::catalog_sync;
+use crate::modules::inventory::ItemRef;
+use crate::modules::item::item;
+use crate::types::{DbVector2, DbVector3};
+use serde::Deserialize;
 use spacetimedb::{ReducerContext, SpacetimeType, Table};
+use std::collections::HashSet;
 
-#[derive(SpacetimeType, Clone, Debug)]
+// Must stay in sync with the Unity BuildingPieceDatabase
+const BUILDING_PIECE_VARIANTS: &str = include_str!("../../data/building_piece_variants.json");
+
+#[derive(SpacetimeType, Deserialize, Clone, Debug)]
 pub struct DbBuildingCost {
     pub item_id: u32,
     pub quantity: u32,
 }
 
+#[derive(SpacetimeType, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DbBuildingMaterialTier {
+    Twig,
+    Wood,
+    Stone,
+    Metal,
+}
+
+#[derive(SpacetimeType, Deserialize, Clone, Debug)]
+pub struct DbBuildingPieceTier {
+    pub tier: DbBuildingMaterialTier,
+    pub build_cost: Vec<DbBuildingCost>,
+    pub max_health: f32,
+}
+
+#[derive(SpacetimeType, Deserialize, Clone, Debug)]
+pub struct DbBuildingStationSlots {
+    pub fuel_slots: u32,
+    pub processing_slots: u32,
+}
+
 #[spacetimedb::table(name = building_piece_variant, public)]
+#[derive(Deserialize, Clone, Debug)]
 pub struct DbBuildingPieceVariant {
     #[primary_key]
     pub variant_id: u32,
     pub piece_type: DbBuildingPieceType,
     pub variant_name: String,
-    pub build_cost: Vec<DbBuildingCost>,
-    pub max_health: f32,
+    // Tiers the piece can be built or upgraded to, lowest first
+    pub tiers: Vec<DbBuildingPieceTier>,
+    pub destroyed_drop_fraction: f32,
+    // Health lost per hour while the piece is abandoned or its claim is unpaid
+    pub decay_per_hour: f32,
+    // Doors, windows and the like that players can open and lock
+    pub interactive: bool,
+    // Item stacks a storage piece can hold, zero for everything else
+    pub storage_slots: u32,
+    // Inventories of crafting stations, None for everything else
+    pub station: Option<DbBuildingStationSlots>,
+    // Convex outline on the x/z plane, relative to the piece's pivot
+    pub footprint: Vec<DbVector2>,
+    pub height: f32,
+    // Mirrors the anchor points on the Unity prefab
+    pub sockets: Vec<DbVector3>,
 }
 
 pub fn building_piece_variant_get(
@@ -25,188 +74,219 @@ pub fn building_piece_variant_get(
         .db
         .building_piece_variant()
         .variant_id()
-        .find(&variant_id)
+        .find(variant_id)
         .ok_or("Building piece variant not found")?;
 
     Ok(variant)
 }
 
-pub fn building_piece_variant_init(ctx: &ReducerContext) -> Result<(), String> {
-    foundation_variants(ctx)?;
-    floor_variants(ctx)?;
-    wall_variants(ctx)?;
-    stair_variants(ctx)?;
-    Ok(())
+pub fn building_piece_variant_tier(
+    variant: &DbBuildingPieceVariant,
+    tier: DbBuildingMaterialTier,
+) -> Result<&DbBuildingPieceTier, String> {
+    variant
+        .tiers
+        .iter()
+        .find(|stats| stats.tier == tier)
+        .ok_or_else(|| "Building piece variant has no such tier".to_string())
 }
 
-fn foundation_variants(ctx: &ReducerContext) -> Result<(), String> {
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 0,
-            piece_type: DbBuildingPieceType::Foundation,
-            variant_name: "Square".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 1,
-            piece_type: DbBuildingPieceType::Foundation,
-            variant_name: "Triangle".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    Ok(())
+// Build cost in the form the inventory works with
+pub fn building_piece_variant_cost(stats: &DbBuildingPieceTier) -> Vec<ItemRef> {
+    stats
+        .build_cost
+        .iter()
+        .map(|cost| ItemRef {
+            id: cost.item_id,
+            quantity: cost.quantity,
+            instance_id: None,
+        })
+        .collect()
 }
 
-fn floor_variants(ctx: &ReducerContext) -> Result<(), String> {
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 2,
-            piece_type: DbBuildingPieceType::Floor,
-            variant_name: "Floor".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 3,
-            piece_type: DbBuildingPieceType::Floor,
-            variant_name: "Half Floor".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 4,
-            piece_type: DbBuildingPieceType::Floor,
-            variant_name: "Quarter Floor".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 5,
-            piece_type: DbBuildingPieceType::Floor,
-            variant_name: "Triangle".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    Ok(())
+pub fn building_piece_variant_init(ctx: &ReducerContext) -> Result<(), String> {
+    building_piece_variant_load(ctx)
 }
 
-fn wall_variants(ctx: &ReducerContext) -> Result<(), String> {
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 6,
-            piece_type: DbBuildingPieceType::Wall,
-            variant_name: "Wall".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 7,
-            piece_type: DbBuildingPieceType::Wall,
-            variant_name: "Half Wall".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 8,
-            piece_type: DbBuildingPieceType::Wall,
-            variant_name: "Quarter Wall".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 9,
-            piece_type: DbBuildingPieceType::Wall,
-            variant_name: "Door".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 10,
-            piece_type: DbBuildingPieceType::Wall,
-            variant_name: "Window".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    Ok(())
+#[spacetimedb::reducer]
+pub fn building_piece_variant_sync(ctx: &ReducerContext) -> Result<(), String> {
+    admin_require(ctx)?;
+    building_piece_variant_load(ctx)
 }
 
-fn stair_variants(ctx: &ReducerContext) -> Result<(), String> {
-    ctx.db
-        .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 11,
-            piece_type: DbBuildingPieceType::Stair,
-            variant_name: "Stair".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
-    ctx.db
+fn building_piece_variant_parse(
+    ctx: &ReducerContext,
+) -> Result<Vec<DbBuildingPieceVariant>, String> {
+    let variants: Vec<DbBuildingPieceVariant> = serde_json::from_str(BUILDING_PIECE_VARIANTS)
+        .map_err(|e| format!("Failed to parse building piece variants: {}", e))?;
+
+    let known_items: HashSet<u32> = ctx.db.item().iter().map(|item| item.id).collect();
+    let mut seen = HashSet::new();
+    for variant in &variants {
+        if !seen.insert(variant.variant_id) {
+            return Err(format!(
+                "Duplicate building piece variant id {}",
+                variant.variant_id
+            ));
+        }
+        if variant.tiers.is_empty() {
+            return Err(format!(
+                "Building piece variant {} has no tiers",
+                variant.variant_id
+            ));
+        }
+        if variant
+            .tiers
+            .windows(2)
+            .any(|pair| pair[0].tier >= pair[1].tier)
+        {
+            return Err(format!(
+                "Building piece variant {} tiers must be unique and in ascending order",
+                variant.variant_id
+            ));
+        }
+        for stats in &variant.tiers {
+            if stats.build_cost.is_empty() {
+                return Err(format!(
+                    "Building piece variant {} has a tier with no build cost",
+                    variant.variant_id
+                ));
+            }
+            for cost in &stats.build_cost {
+                if !known_items.contains(&cost.item_id) {
+                    return Err(format!(
+                        "Building piece variant {} costs unknown item {}",
+                        variant.variant_id, cost.item_id
+                    ));
+                }
+                if cost.quantity == 0 {
+                    return Err(format!(
+                        "Building piece variant {} has a zero quantity cost",
+                        variant.variant_id
+                    ));
+                }
+            }
+            if stats.max_health <= 0.0 {
+                return Err(format!(
+                    "Building piece variant {} must have positive max health",
+                    variant.variant_id
+                ));
+            }
+        }
+        if variant.decay_per_hour < 0.0 {
+            return Err(format!(
+                "Building piece variant {} can't have negative decay",
+                variant.variant_id
+            ));
+        }
+        if variant.footprint.len() < 3 || variant.height <= 0.0 {
+            return Err(format!(
+                "Building piece variant {} has an invalid footprint",
+                variant.variant_id
+            ));
+        }
+        if variant.sockets.is_empty() {
+            return Err(format!(
+                "Building piece variant {} has no sockets",
+                variant.variant_id
+            ));
+        }
+        if (variant.piece_type == DbBuildingPieceType::Storage) != (variant.storage_slots > 0) {
+            return Err(format!(
+                "Building piece variant {} storage slots don't match its type",
+                variant.variant_id
+            ));
+        }
+        let is_station = matches!(
+            variant.piece_type,
+            DbBuildingPieceType::Workbench
+                | DbBuildingPieceType::Forge
+                | DbBuildingPieceType::Anvil
+        );
+        let has_processing = variant
+            .station
+            .as_ref()
+            .is_some_and(|station| station.processing_slots > 0);
+        if is_station != variant.station.is_some() || is_station != has_processing {
+            return Err(format!(
+                "Building piece variant {} station slots don't match its type",
+                variant.variant_id
+            ));
+        }
+        if !(0.0..=1.0).contains(&variant.destroyed_drop_fraction) {
+            return Err(format!(
+                "Building piece variant {} drop fraction must be between 0 and 1",
+                variant.variant_id
+            ));
+        }
+    }
+
+    Ok(variants)
+}
+
+// Upserts every variant from the catalog and removes variants that were
+// dropped from it, as long as no placed piece still references them
+fn building_piece_variant_load(ctx: &ReducerContext) -> Result<(), String> {
+    let current_ids = ctx
+        .db
         .building_piece_variant()
-        .insert(DbBuildingPieceVariant {
-            variant_id: 12,
-            piece_type: DbBuildingPieceType::Stair,
-            variant_name: "Half Stair".to_string(),
-            build_cost: vec![DbBuildingCost {
-                item_id: 0,
-                quantity: 5,
-            }],
-            max_health: 100.0,
-        });
+        .iter()
+        .map(|variant| variant.variant_id)
+        .collect();
+    catalog_sync(
+        building_piece_variant_parse(ctx)?,
+        current_ids,
+        |variant| variant.variant_id,
+        |variant| {
+            ctx.db.building_piece_variant().insert(variant);
+        },
+        |variant| {
+            // Keep placed pieces within the new max health of their tier,
+            // pieces whose tier was dropped fall back to the lowest one
+            let placed: Vec<_> = ctx
+                .db
+                .building_piece_placed()
+                .iter()
+                .filter(|piece| piece.variant_id == variant.variant_id)
+                .collect();
+            for mut piece in placed {
+                let stats = building_piece_variant_tier(&variant, piece.tier)
+                    .unwrap_or(&variant.tiers[0])
+                    .clone();
+                // Give pieces of newly interactive variants their state
+                if variant.interactive {
+                    building_piece_state_create(ctx, piece.piece_id);
+                } else {
+                    building_piece_state_delete(ctx, piece.piece_id);
+                }
+                if piece.tier != stats.tier || piece.health > stats.max_health {
+                    piece.tier = stats.tier;
+                    piece.health = piece.health.min(stats.max_health);
+                    ctx.db.building_piece_placed().piece_id().update(piece);
+                }
+            }
+            ctx.db.building_piece_variant().variant_id().update(variant);
+        },
+        |variant_id| {
+            let in_use = ctx
+                .db
+                .building_piece_placed()
+                .iter()
+                .any(|piece| piece.variant_id == variant_id);
+            if in_use {
+                log::warn!(
+                    "Building piece variant {} was removed from the catalog but is still placed",
+                    variant_id
+                );
+            } else {
+                ctx.db
+                    .building_piece_variant()
+                    .variant_id()
+                    .delete(variant_id);
+            }
+        },
+    );
+
+    log::info!("Building piece variants synced");
     Ok(())
 }
@@ -0,0 +1,403 @@
+use crate::modules::building_chunk::building_chunk_pieces_in_radius;
+use crate::modules::building_geometry::{
+    building_geometry_connected, building_geometry_shape, BuildingShape, NEIGHBOUR_RADIUS,
+};
+use crate::modules::building_piece_placed::{
+    building_piece_despawn, building_piece_placed, DbBuildingPiecePlaced, DbBuildingPieceType,
+};
+use crate::modules::building_piece_variant::{
+    building_piece_variant_cost, building_piece_variant_get, building_piece_variant_tier,
+    DbBuildingPieceVariant,
+};
+use crate::modules::inventory::inventory_give_or_drop;
+use crate::types::DbVector3;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+// How far above a piece its supporter is allowed to sit
+const SUPPORT_HEIGHT_TOLERANCE: f32 = 0.1;
+// Pieces below this stability can't be placed and collapse when reached
+pub const MIN_STABILITY: f32 = 0.1;
+pub const GROUNDED_STABILITY: f32 = 1.0;
+
+#[spacetimedb::table(name = building_piece_support, public)]
+pub struct DbBuildingPieceSupport {
+    #[primary_key]
+    #[auto_inc]
+    pub support_id: u64,
+    #[index(btree)]
+    pub piece_id: u32,
+    #[index(btree)]
+    pub supported_by: u32,
+}
+
+pub struct SupportRule {
+    pub grounded: bool,
+    pub supported_by: &'static [DbBuildingPieceType],
+    pub stability_loss: f32,
+}
+
+pub fn support_rule(piece_type: DbBuildingPieceType) -> SupportRule {
+    match piece_type {
+        DbBuildingPieceType::Foundation => SupportRule {
+            grounded: true,
+            supported_by: &[],
+            stability_loss: 0.0,
+        },
+        DbBuildingPieceType::Wall => SupportRule {
+            grounded: false,
+            supported_by: &[
+                DbBuildingPieceType::Foundation,
+                DbBuildingPieceType::Wall,
+                DbBuildingPieceType::Floor,
+            ],
+            stability_loss: 0.1,
+        },
+        DbBuildingPieceType::Floor => SupportRule {
+            grounded: false,
+            supported_by: &[
+                DbBuildingPieceType::Foundation,
+                DbBuildingPieceType::Wall,
+                DbBuildingPieceType::Floor,
+                DbBuildingPieceType::Stair,
+            ],
+            stability_loss: 0.2,
+        },
+        DbBuildingPieceType::Claim => SupportRule {
+            grounded: true,
+            supported_by: &[],
+            stability_loss: 0.0,
+        },
+        DbBuildingPieceType::Stair => SupportRule {
+            grounded: false,
+            supported_by: &[
+                DbBuildingPieceType::Foundation,
+                DbBuildingPieceType::Wall,
+                DbBuildingPieceType::Floor,
+            ],
+            stability_loss: 0.2,
+        },
+        DbBuildingPieceType::Storage
+        | DbBuildingPieceType::Workbench
+        | DbBuildingPieceType::Forge
+        | DbBuildingPieceType::Anvil => SupportRule {
+            grounded: false,
+            supported_by: &[DbBuildingPieceType::Foundation, DbBuildingPieceType::Floor],
+            stability_loss: 0.0,
+        },
+    }
+}
+
+fn piece_shape_of(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+) -> Result<(DbBuildingPieceType, BuildingShape), String> {
+    let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+    let shape = building_geometry_shape(&variant, &piece.position, &piece.rotation);
+    Ok((variant.piece_type, shape))
+}
+
+// Supports have to share a socket with the piece and can't sit above it
+fn can_support(
+    piece_type: DbBuildingPieceType,
+    shape: &BuildingShape,
+    support_type: DbBuildingPieceType,
+    support_shape: &BuildingShape,
+) -> bool {
+    support_rule(piece_type)
+        .supported_by
+        .contains(&support_type)
+        && support_shape.bottom <= shape.bottom + SUPPORT_HEIGHT_TOLERANCE
+        && building_geometry_connected(shape, support_shape)
+}
+
+// Finds every placed piece that could hold up the given variant at the given transform
+pub fn building_stability_find_supports(
+    ctx: &ReducerContext,
+    variant: &DbBuildingPieceVariant,
+    position: &DbVector3,
+    rotation: &DbVector3,
+    ignore_piece_id: Option<u32>,
+) -> Result<Vec<DbBuildingPiecePlaced>, String> {
+    let shape = building_geometry_shape(variant, position, rotation);
+    let mut supports = Vec::new();
+    for other in building_chunk_pieces_in_radius(ctx, position, NEIGHBOUR_RADIUS) {
+        if Some(other.piece_id) == ignore_piece_id {
+            continue;
+        }
+        let (other_type, other_shape) = piece_shape_of(ctx, &other)?;
+        if can_support(variant.piece_type, &shape, other_type, &other_shape) {
+            supports.push(other);
+        }
+    }
+    Ok(supports)
+}
+
+// Computes the stability a new piece would have, given the pieces supporting it
+pub fn building_stability_compute(
+    piece_type: DbBuildingPieceType,
+    supports: &[DbBuildingPiecePlaced],
+) -> f32 {
+    let rule = support_rule(piece_type);
+    if rule.grounded {
+        return GROUNDED_STABILITY;
+    }
+
+    supports
+        .iter()
+        .map(|support| support.stability - rule.stability_loss)
+        .fold(0.0, f32::max)
+}
+
+// Records the support edges of a freshly placed piece, in both directions,
+// and raises the stability of any neighbours that are now better supported
+pub fn building_stability_connect(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+) -> Result<(), String> {
+    let (piece_type, shape) = piece_shape_of(ctx, piece)?;
+
+    for other in building_chunk_pieces_in_radius(ctx, &piece.position, NEIGHBOUR_RADIUS) {
+        if other.piece_id == piece.piece_id {
+            continue;
+        }
+        let (other_type, other_shape) = piece_shape_of(ctx, &other)?;
+
+        if can_support(piece_type, &shape, other_type, &other_shape) {
+            ctx.db
+                .building_piece_support()
+                .insert(DbBuildingPieceSupport {
+                    support_id: 0,
+                    piece_id: piece.piece_id,
+                    supported_by: other.piece_id,
+                });
+        }
+        if can_support(other_type, &other_shape, piece_type, &shape) {
+            ctx.db
+                .building_piece_support()
+                .insert(DbBuildingPieceSupport {
+                    support_id: 0,
+                    piece_id: other.piece_id,
+                    supported_by: piece.piece_id,
+                });
+        }
+    }
+
+    building_stability_recompute(ctx, vec![piece.piece_id])?;
+    Ok(())
+}
+
+// Rewires a piece that moved: drops its old edges, connects it where it is now
+// and collapses anything that was relying on it at its old spot
+pub fn building_stability_reconnect(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+) -> Result<(), String> {
+    let dependents: Vec<u32> = ctx
+        .db
+        .building_piece_support()
+        .supported_by()
+        .filter(piece.piece_id)
+        .map(|support| support.piece_id)
+        .collect();
+
+    building_stability_remove_edges(ctx, piece.piece_id);
+    building_stability_connect(ctx, piece)?;
+    building_stability_recompute(ctx, dependents)?;
+    Ok(())
+}
+
+// Removes a piece from the support graph and collapses anything that was relying on it
+pub fn building_stability_disconnect(ctx: &ReducerContext, piece_id: u32) -> Result<(), String> {
+    let dependents: Vec<u32> = ctx
+        .db
+        .building_piece_support()
+        .supported_by()
+        .filter(piece_id)
+        .map(|support| support.piece_id)
+        .collect();
+
+    building_stability_remove_edges(ctx, piece_id);
+    building_stability_recompute(ctx, dependents)?;
+    Ok(())
+}
+
+fn building_stability_remove_edges(ctx: &ReducerContext, piece_id: u32) {
+    ctx.db.building_piece_support().piece_id().delete(piece_id);
+    ctx.db
+        .building_piece_support()
+        .supported_by()
+        .delete(piece_id);
+}
+
+// Re-evaluates the stability of the given pieces and everything they support,
+// collapsing pieces that end up below the minimum stability
+fn building_stability_recompute(ctx: &ReducerContext, roots: Vec<u32>) -> Result<(), String> {
+    // Gather every piece that transitively depends on the roots
+    let mut affected = HashSet::new();
+    let mut queue: VecDeque<u32> = roots.into_iter().collect();
+    while let Some(piece_id) = queue.pop_front() {
+        if !affected.insert(piece_id) {
+            continue;
+        }
+        for support in ctx
+            .db
+            .building_piece_support()
+            .supported_by()
+            .filter(piece_id)
+        {
+            queue.push_back(support.piece_id);
+        }
+    }
+
+    // Load the affected pieces, grounded ones keep their stability as-is
+    let mut pieces = HashMap::new();
+    let mut stability = HashMap::new();
+    for piece_id in &affected {
+        if let Some(piece) = ctx.db.building_piece_placed().piece_id().find(piece_id) {
+            let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+            let rule = support_rule(variant.piece_type);
+            let initial = if rule.grounded {
+                GROUNDED_STABILITY
+            } else {
+                0.0
+            };
+            stability.insert(*piece_id, initial);
+            pieces.insert(*piece_id, (piece, rule));
+        }
+    }
+
+    // Relax until no piece can get any more support from its neighbours
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (piece_id, (_, rule)) in &pieces {
+            if rule.grounded {
+                continue;
+            }
+            let mut best = 0.0;
+            for support in ctx.db.building_piece_support().piece_id().filter(piece_id) {
+                let support_stability = match stability.get(&support.supported_by) {
+                    Some(value) => *value,
+                    None => ctx
+                        .db
+                        .building_piece_placed()
+                        .piece_id()
+                        .find(support.supported_by)
+                        .map(|piece| piece.stability)
+                        .unwrap_or(0.0),
+                };
+                best = f32::max(best, support_stability - rule.stability_loss);
+            }
+            if best > stability[piece_id] {
+                stability.insert(*piece_id, best);
+                changed = true;
+            }
+        }
+    }
+
+    // Persist the new values and collapse anything that can no longer stand
+    for (piece_id, (mut piece, _)) in pieces {
+        let new_stability = stability[&piece_id];
+        if new_stability < MIN_STABILITY {
+            building_stability_collapse(ctx, piece)?;
+        } else if new_stability != piece.stability {
+            piece.stability = new_stability;
+            ctx.db.building_piece_placed().piece_id().update(piece);
+        }
+    }
+    Ok(())
+}
+
+fn building_stability_collapse(
+    ctx: &ReducerContext,
+    piece: DbBuildingPiecePlaced,
+) -> Result<(), String> {
+    log::debug!("Building piece {} collapsed", piece.piece_id);
+
+    // Refund the materials to the owner of the collapsed piece
+    let variant = building_piece_variant_get(ctx, piece.variant_id)?;
+    let stats = building_piece_variant_tier(&variant, piece.tier)?;
+    inventory_give_or_drop(
+        ctx,
+        piece.owner,
+        &building_piece_variant_cost(stats),
+        &piece.position,
+    );
+
+    building_stability_remove_edges(ctx, piece.piece_id);
+    building_piece_despawn(ctx, &piece)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::building_piece_variant::DbBuildingMaterialTier;
+    use spacetimedb::{Identity, Timestamp};
+
+    fn support(stability: f32) -> DbBuildingPiecePlaced {
+        DbBuildingPiecePlaced {
+            piece_id: 1,
+            owner: Identity::ZERO,
+            variant_id: 1,
+            tier: DbBuildingMaterialTier::Twig,
+            position: DbVector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            rotation: DbVector3 {
+                x: 0.0,
+                y: 0.0,
+                z: 0.0,
+            },
+            stability,
+            health: 100.0,
+            chunk_x: 0,
+            chunk_z: 0,
+            placed_at: Timestamp::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn compute_gives_grounded_pieces_full_stability() {
+        assert_eq!(
+            building_stability_compute(DbBuildingPieceType::Foundation, &[]),
+            GROUNDED_STABILITY
+        );
+        assert_eq!(
+            building_stability_compute(DbBuildingPieceType::Claim, &[support(0.2)]),
+            GROUNDED_STABILITY
+        );
+    }
+
+    #[test]
+    fn compute_takes_the_best_support_minus_the_loss() {
+        let supports = [support(0.5), support(0.9), support(0.7)];
+        let stability = building_stability_compute(DbBuildingPieceType::Wall, &supports);
+        assert!((stability - 0.8).abs() < 1e-6);
+    }
+
+    #[test]
+    fn compute_gives_unsupported_pieces_no_stability() {
+        assert_eq!(
+            building_stability_compute(DbBuildingPieceType::Floor, &[]),
+            0.0
+        );
+    }
+
+    #[test]
+    fn compute_never_goes_negative() {
+        let stability = building_stability_compute(DbBuildingPieceType::Floor, &[support(0.1)]);
+        assert_eq!(stability, 0.0);
+    }
+
+    #[test]
+    fn compute_loses_stability_with_every_piece_in_a_chain() {
+        let first = building_stability_compute(DbBuildingPieceType::Wall, &[support(1.0)]);
+        let second = building_stability_compute(DbBuildingPieceType::Wall, &[support(first)]);
+        assert!((first - 0.9).abs() < 1e-6);
+        assert!((second - 0.8).abs() < 1e-6);
+    }
+}
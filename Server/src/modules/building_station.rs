@@ -0,0 +1,204 @@
+use crate::modules::building_chunk::building_chunk_pieces_in_radius;
+use crate::modules::building_piece_placed::{building_piece_placed, DbBuildingPiecePlaced};
+use crate::modules::building_piece_variant::DbBuildingStationSlots;
+use crate::modules::building_storage::{
+    building_storage_deposit, building_storage_drop, building_storage_require_access,
+    building_storage_return, building_storage_withdraw,
+};
+use crate::modules::crafting::crafting_deliver_to_owner;
+use crate::modules::inventory::{
+    inventory_give_or_drop, inventory_slots_give_many, inventory_slots_take_many, ItemRef,
+};
+use crate::modules::item_instance::item_instance_delete;
+use crate::modules::land_claim::{land_claim_at, land_claim_has_access, DbLandClaimAccess};
+use crate::modules::player::player;
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table};
+
+// How close a player has to stand to use a station
+pub const STATION_RANGE: f32 = 4.0;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbBuildingStationInventory {
+    Fuel,
+    Processing,
+}
+
+// Fuel burned by the station's recipes and the processing slots its
+// finished crafts are delivered to
+#[spacetimedb::table(name = building_station, public)]
+pub struct DbBuildingStation {
+    #[primary_key]
+    pub piece_id: u32,
+    pub fuel: Vec<Option<ItemRef>>,
+    pub processing: Vec<Option<ItemRef>>,
+}
+
+impl DbBuildingStation {
+    fn slots_mut(&mut self, inventory: DbBuildingStationInventory) -> &mut Vec<Option<ItemRef>> {
+        match inventory {
+            DbBuildingStationInventory::Fuel => &mut self.fuel,
+            DbBuildingStationInventory::Processing => &mut self.processing,
+        }
+    }
+}
+
+pub fn building_station_create(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    slots: &DbBuildingStationSlots,
+) {
+    ctx.db.building_station().insert(DbBuildingStation {
+        piece_id,
+        fuel: vec![None; slots.fuel_slots as usize],
+        processing: vec![None; slots.processing_slots as usize],
+    });
+}
+
+// Spills everything in the station onto the ground around the piece
+pub fn building_station_drop(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+    locked_to: Option<Identity>,
+) {
+    if let Some(mut station) = ctx.db.building_station().piece_id().find(piece.piece_id) {
+        building_storage_drop(
+            ctx,
+            [&mut station.fuel, &mut station.processing],
+            piece,
+            locked_to,
+        );
+        ctx.db.building_station().piece_id().update(station);
+    }
+}
+
+pub fn building_station_delete(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+) -> Result<(), String> {
+    if let Some(station) = ctx.db.building_station().piece_id().find(piece.piece_id) {
+        building_storage_return(ctx, [&station.fuel, &station.processing], piece);
+        ctx.db.building_station().piece_id().delete(piece.piece_id);
+    }
+    Ok(())
+}
+
+// Finds a placed station of the variant next to the sender that they are
+// allowed to use
+pub fn building_station_find_usable(
+    ctx: &ReducerContext,
+    variant_id: u32,
+) -> Result<DbBuildingPiecePlaced, String> {
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    if !player.online {
+        return Err("Player is not online".to_string());
+    }
+
+    building_chunk_pieces_in_radius(ctx, &player.position, STATION_RANGE)
+        .into_iter()
+        .filter(|piece| piece.variant_id == variant_id)
+        .find(|piece| {
+            land_claim_at(ctx, &piece.position).is_none_or(|claim| {
+                land_claim_has_access(ctx, &claim, ctx.sender, DbLandClaimAccess::Access)
+            })
+        })
+        .ok_or("This recipe needs a crafting station nearby".to_string())
+}
+
+pub fn building_station_take_fuel(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    fuel: &[ItemRef],
+) -> Result<(), String> {
+    let mut station = ctx
+        .db
+        .building_station()
+        .piece_id()
+        .find(piece_id)
+        .ok_or("Building piece is not a station")?;
+    let Ok(burned) = inventory_slots_take_many(&mut station.fuel, fuel) else {
+        return Err("Not enough fuel in the station".to_string());
+    };
+    for instance_id in burned.iter().filter_map(|item| item.instance_id) {
+        item_instance_delete(ctx, instance_id);
+    }
+    ctx.db.building_station().piece_id().update(station);
+    Ok(())
+}
+
+// Puts finished crafts into the station, what doesn't fit goes to the owner
+pub fn building_station_deliver(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    owner: Identity,
+    outputs: &[ItemRef],
+) {
+    let Some(piece) = ctx.db.building_piece_placed().piece_id().find(piece_id) else {
+        crafting_deliver_to_owner(ctx, owner, outputs);
+        return;
+    };
+    if let Some(mut station) = ctx.db.building_station().piece_id().find(piece_id) {
+        if inventory_slots_give_many(ctx, &mut station.processing, outputs).is_ok() {
+            ctx.db.building_station().piece_id().update(station);
+            return;
+        }
+    }
+    inventory_give_or_drop(ctx, owner, outputs, &piece.position);
+}
+
+fn building_station_get(ctx: &ReducerContext, piece_id: u32) -> Result<DbBuildingStation, String> {
+    let station = ctx
+        .db
+        .building_station()
+        .piece_id()
+        .find(piece_id)
+        .ok_or("Building piece is not a station")?;
+    building_storage_require_access(ctx, piece_id, STATION_RANGE)?;
+    Ok(station)
+}
+
+#[spacetimedb::reducer]
+pub fn building_station_deposit(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    inventory: DbBuildingStationInventory,
+    item_id: u32,
+    instance_id: Option<u64>,
+    quantity: u32,
+) -> Result<(), String> {
+    let mut station = building_station_get(ctx, piece_id)?;
+
+    let item = ItemRef {
+        id: item_id,
+        quantity,
+        instance_id,
+    };
+    building_storage_deposit(ctx, station.slots_mut(inventory), item, "Station is full")?;
+    ctx.db.building_station().piece_id().update(station);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn building_station_withdraw(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    inventory: DbBuildingStationInventory,
+    item_id: u32,
+    instance_id: Option<u64>,
+    quantity: u32,
+) -> Result<(), String> {
+    let mut station = building_station_get(ctx, piece_id)?;
+
+    let item = ItemRef {
+        id: item_id,
+        quantity,
+        instance_id,
+    };
+    building_storage_withdraw(ctx, station.slots_mut(inventory), item)?;
+    ctx.db.building_station().piece_id().update(station);
+    Ok(())
+}
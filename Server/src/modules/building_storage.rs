@@ -0,0 +1,86 @@
+use crate::modules::building_piece_placed::{building_piece_placed, DbBuildingPiecePlaced};
+use crate::modules::inventory::{
+    inventory_give_many, inventory_give_or_drop, inventory_slots_give_many,
+    inventory_slots_take_many, inventory_take_many, InventoryError, ItemRef,
+};
+use crate::modules::land_claim::{land_claim_check, DbLandClaimAccess};
+use crate::modules::player::player_require_in_range;
+use crate::modules::world_item::world_item_spawn;
+use spacetimedb::{Identity, ReducerContext};
+
+// Shared handling for placed pieces that keep items in slots, like containers
+// and stations. Callers load and save their own rows around these
+
+// Rejects the action unless the sender is next to the piece and may access its claim
+pub fn building_storage_require_access(
+    ctx: &ReducerContext,
+    piece_id: u32,
+    range: f32,
+) -> Result<(), String> {
+    let piece = ctx
+        .db
+        .building_piece_placed()
+        .piece_id()
+        .find(piece_id)
+        .ok_or("Building piece not found")?;
+
+    player_require_in_range(ctx, &piece.position, range)?;
+    land_claim_check(ctx, &piece.position, DbLandClaimAccess::Access)?;
+    Ok(())
+}
+
+// Moves items from the sender's inventory into the slots
+pub fn building_storage_deposit(
+    ctx: &ReducerContext,
+    slots: &mut Vec<Option<ItemRef>>,
+    item: ItemRef,
+    full_error: &str,
+) -> Result<(), String> {
+    if item.quantity == 0 {
+        return Err(InventoryError::InvalidQuantity.into());
+    }
+    let items = inventory_take_many(ctx, ctx.sender, &[item])?;
+    match inventory_slots_give_many(ctx, slots, &items) {
+        Err(InventoryError::InventoryFull) => Err(full_error.to_string()),
+        result => Ok(result?),
+    }
+}
+
+// Moves items from the slots into the sender's inventory
+pub fn building_storage_withdraw(
+    ctx: &ReducerContext,
+    slots: &mut Vec<Option<ItemRef>>,
+    item: ItemRef,
+) -> Result<(), String> {
+    if item.quantity == 0 {
+        return Err(InventoryError::InvalidQuantity.into());
+    }
+    let items = inventory_slots_take_many(slots, &[item])?;
+    inventory_give_many(ctx, ctx.sender, &items)?;
+    Ok(())
+}
+
+// Spills everything in the slots onto the ground around the piece
+pub fn building_storage_drop<'a>(
+    ctx: &ReducerContext,
+    slots: impl IntoIterator<Item = &'a mut Vec<Option<ItemRef>>>,
+    piece: &DbBuildingPiecePlaced,
+    locked_to: Option<Identity>,
+) {
+    for slots in slots {
+        for item in slots.iter().flatten() {
+            world_item_spawn(ctx, item, &piece.position, locked_to);
+        }
+        slots.fill(None);
+    }
+}
+
+// Hands anything still in the slots back to the piece owner
+pub fn building_storage_return<'a>(
+    ctx: &ReducerContext,
+    slots: impl IntoIterator<Item = &'a Vec<Option<ItemRef>>>,
+    piece: &DbBuildingPiecePlaced,
+) {
+    let items: Vec<ItemRef> = slots.into_iter().flatten().flatten().cloned().collect();
+    inventory_give_or_drop(ctx, piece.owner, &items, &piece.position);
+}
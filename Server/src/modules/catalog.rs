@@ -0,0 +1,30 @@
+use std::collections::HashSet;
+
+// Brings a table in line with its JSON catalog. Catalog rows are updated when
+// their id is already in the table and inserted otherwise, ids that were dropped
+// from the catalog go to `remove_stale`, which decides whether they can be deleted
+pub fn catalog_sync<T>(
+    catalog: Vec<T>,
+    current_ids: Vec<u32>,
+    id_of: impl Fn(&T) -> u32,
+    mut insert: impl FnMut(T),
+    mut update: impl FnMut(T),
+    mut remove_stale: impl FnMut(u32),
+) {
+    let catalog_ids: HashSet<u32> = catalog.iter().map(&id_of).collect();
+    let existing: HashSet<u32> = current_ids.iter().copied().collect();
+
+    for row in catalog {
+        if existing.contains(&id_of(&row)) {
+            update(row);
+        } else {
+            insert(row);
+        }
+    }
+
+    for id in current_ids {
+        if !catalog_ids.contains(&id) {
+            remove_stale(id);
+        }
+    }
+}
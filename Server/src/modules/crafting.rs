@@ -0,0 +1,146 @@
+use crate::modules::building_station::{
+    building_station_deliver, building_station_find_usable, building_station_take_fuel,
+};
+use crate::modules::inventory::{
+    inventory_consume_many, inventory_give_many, inventory_give_or_drop, ItemRef,
+};
+use crate::modules::item_instance::item_instance_craft;
+use crate::modules::player::player;
+use crate::modules::recipe::recipe_get;
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+use std::time::Duration;
+
+const CRAFTING_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+const CRAFTING_QUEUE_LIMIT: usize = 10;
+
+// Crafts run one after another per player, each entry keeps the items it
+// was started with so recipe changes don't affect queued crafts
+#[spacetimedb::table(name = crafting_queue, public)]
+pub struct DbCraftingQueue {
+    #[primary_key]
+    #[auto_inc]
+    pub craft_id: u64,
+    #[index(btree)]
+    pub owner: Identity,
+    pub recipe_id: u32,
+    pub inputs: Vec<ItemRef>,
+    pub outputs: Vec<ItemRef>,
+    // Station the outputs are delivered to, if the recipe needs one
+    pub station_piece_id: Option<u32>,
+    pub completes_at: Timestamp,
+}
+
+#[spacetimedb::table(name = crafting_schedule, scheduled(crafting_tick))]
+pub struct CraftingSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+pub fn crafting_init(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db.crafting_schedule().insert(CraftingSchedule {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Interval(TimeDuration::from(CRAFTING_CHECK_INTERVAL)),
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn craft_start(ctx: &ReducerContext, recipe_id: u32, count: u32) -> Result<(), String> {
+    let recipe = recipe_get(ctx, recipe_id)?;
+    if count == 0 {
+        return Err("Count must be greater than zero".to_string());
+    }
+    let station = match recipe.station_variant_id {
+        Some(variant_id) => Some(building_station_find_usable(ctx, variant_id)?),
+        None => None,
+    };
+
+    let queued: Vec<DbCraftingQueue> = ctx.db.crafting_queue().owner().filter(ctx.sender).collect();
+    // Compared this way round so a huge count can't overflow the sum
+    if count as usize > CRAFTING_QUEUE_LIMIT.saturating_sub(queued.len()) {
+        return Err("Crafting queue is full".to_string());
+    }
+
+    // Take the inputs for every craft at once, so either all start or none
+    let inputs: Vec<ItemRef> = (0..count).flat_map(|_| recipe.inputs.clone()).collect();
+    inventory_consume_many(ctx, ctx.sender, &inputs)?;
+    if let (Some(station), Some(fuel)) = (&station, &recipe.fuel) {
+        let fuel: Vec<ItemRef> = (0..count).map(|_| fuel.clone()).collect();
+        building_station_take_fuel(ctx, station.piece_id, &fuel)?;
+    }
+
+    // Queue after whatever is already being crafted
+    let mut completes_at = queued
+        .iter()
+        .map(|craft| craft.completes_at)
+        .max()
+        .filter(|last| *last > ctx.timestamp)
+        .unwrap_or(ctx.timestamp);
+    for _ in 0..count {
+        completes_at += Duration::from_secs(recipe.craft_seconds as u64);
+        ctx.db.crafting_queue().insert(DbCraftingQueue {
+            craft_id: 0,
+            owner: ctx.sender,
+            recipe_id,
+            inputs: recipe.inputs.clone(),
+            outputs: recipe.outputs.clone(),
+            station_piece_id: station.as_ref().map(|station| station.piece_id),
+            completes_at,
+        });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn craft_cancel(ctx: &ReducerContext, craft_id: u64) -> Result<(), String> {
+    let craft = ctx
+        .db
+        .crafting_queue()
+        .craft_id()
+        .find(craft_id)
+        .ok_or("Craft not found")?;
+    if craft.owner != ctx.sender {
+        return Err("Only the owner can cancel their crafts".to_string());
+    }
+
+    inventory_give_many(ctx, ctx.sender, &craft.inputs)?;
+    ctx.db.crafting_queue().craft_id().delete(craft_id);
+    Ok(())
+}
+
+// Outputs that don't fit are dropped at the owner's feet
+pub fn crafting_deliver_to_owner(ctx: &ReducerContext, owner: Identity, outputs: &[ItemRef]) {
+    let position = ctx
+        .db
+        .player()
+        .identity()
+        .find(owner)
+        .map(|player| player.position)
+        .unwrap_or_default();
+    inventory_give_or_drop(ctx, owner, outputs, &position);
+}
+
+#[spacetimedb::reducer]
+pub fn crafting_tick(ctx: &ReducerContext, _schedule: CraftingSchedule) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Crafts can only be completed by the scheduler".to_string());
+    }
+
+    let completed: Vec<DbCraftingQueue> = ctx
+        .db
+        .crafting_queue()
+        .iter()
+        .filter(|craft| craft.completes_at <= ctx.timestamp)
+        .collect();
+    for craft in completed {
+        let outputs = item_instance_craft(ctx, &craft.outputs, craft.owner);
+        match craft.station_piece_id {
+            Some(piece_id) => building_station_deliver(ctx, piece_id, craft.owner, &outputs),
+            None => crafting_deliver_to_owner(ctx, craft.owner, &outputs),
+        }
+        ctx.db.crafting_queue().craft_id().delete(craft.craft_id);
+    }
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use crate::modules::inventory::{
+    inventory_return_many, inventory_slot_get, inventory_slot_set, ItemRef,
+};
+use crate::modules::item::{item, DbEquipmentSlot, DbItemEquipment};
+use crate::modules::item_instance::{item_instance_stats, item_instance_wear};
+use crate::modules::trade::trade_instance_changed;
+use crate::modules::world_item::world_item_spawn;
+use crate::types::DbVector3;
+use spacetimedb::{Identity, ReducerContext, Table};
+
+const UNARMED_DAMAGE: f32 = 5.0;
+const UNARMED_HARVEST_POWER: u32 = 1;
+// Armor at which incoming damage is halved
+const ARMOR_HALF_DAMAGE: f32 = 50.0;
+
+// Items equipped per slot, equipped items are no longer in the inventory
+#[spacetimedb::table(name = equipment, public)]
+pub struct DbEquipment {
+    #[primary_key]
+    pub identity: Identity,
+    pub main_hand: Option<ItemRef>,
+    pub off_hand: Option<ItemRef>,
+    pub head: Option<ItemRef>,
+    pub chest: Option<ItemRef>,
+    pub legs: Option<ItemRef>,
+    pub tool: Option<ItemRef>,
+}
+
+impl DbEquipment {
+    fn slot_mut(&mut self, slot: DbEquipmentSlot) -> &mut Option<ItemRef> {
+        match slot {
+            DbEquipmentSlot::MainHand => &mut self.main_hand,
+            DbEquipmentSlot::OffHand => &mut self.off_hand,
+            DbEquipmentSlot::Head => &mut self.head,
+            DbEquipmentSlot::Chest => &mut self.chest,
+            DbEquipmentSlot::Legs => &mut self.legs,
+            DbEquipmentSlot::Tool => &mut self.tool,
+        }
+    }
+
+    pub fn items(&self) -> impl Iterator<Item = &ItemRef> {
+        [
+            &self.main_hand,
+            &self.off_hand,
+            &self.head,
+            &self.chest,
+            &self.legs,
+            &self.tool,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+fn equipment_empty(identity: Identity) -> DbEquipment {
+    DbEquipment {
+        identity,
+        main_hand: None,
+        off_hand: None,
+        head: None,
+        chest: None,
+        legs: None,
+        tool: None,
+    }
+}
+
+// Also called for players that joined before equipment existed
+pub fn equipment_create(ctx: &ReducerContext, identity: Identity) {
+    if ctx.db.equipment().identity().find(identity).is_none() {
+        ctx.db.equipment().insert(equipment_empty(identity));
+    }
+}
+
+fn equipment_equipped_stats(ctx: &ReducerContext, identity: Identity) -> Vec<DbItemEquipment> {
+    ctx.db
+        .equipment()
+        .identity()
+        .find(identity)
+        .map(|equipment| {
+            equipment
+                .items()
+                .filter_map(|item| item_instance_stats(ctx, item))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+// Damage dealt with whatever is in the main hand
+pub fn equipment_damage(ctx: &ReducerContext, identity: Identity) -> f32 {
+    equipment_equipped_stats(ctx, identity)
+        .iter()
+        .find(|stats| stats.slot == DbEquipmentSlot::MainHand)
+        .map_or(UNARMED_DAMAGE, |stats| stats.damage)
+}
+
+// Shrinks incoming damage by the armor of everything equipped
+pub fn equipment_reduce_damage(ctx: &ReducerContext, identity: Identity, damage: f32) -> f32 {
+    let armor: f32 = equipment_equipped_stats(ctx, identity)
+        .iter()
+        .map(|stats| stats.armor)
+        .sum();
+    damage * ARMOR_HALF_DAMAGE / (ARMOR_HALF_DAMAGE + armor)
+}
+
+pub fn equipment_harvest_power(ctx: &ReducerContext, identity: Identity) -> u32 {
+    equipment_equipped_stats(ctx, identity)
+        .iter()
+        .find(|stats| stats.slot == DbEquipmentSlot::Tool)
+        .map_or(UNARMED_HARVEST_POWER, |stats| {
+            stats.harvest_power.max(UNARMED_HARVEST_POWER)
+        })
+}
+
+// Counted towards the carry weight like the inventory
+pub fn equipment_weight(ctx: &ReducerContext, identity: Identity) -> f32 {
+    ctx.db
+        .equipment()
+        .identity()
+        .find(identity)
+        .map(|equipment| {
+            equipment
+                .items()
+                .filter_map(|item| ctx.db.item().id().find(item.id))
+                .map(|item| item.weight)
+                .sum()
+        })
+        .unwrap_or(0.0)
+}
+
+// Spills everything equipped onto the ground
+pub fn equipment_drop_all(ctx: &ReducerContext, identity: Identity, position: &DbVector3) {
+    if let Some(equipment) = ctx.db.equipment().identity().find(identity) {
+        for item in equipment.items() {
+            world_item_spawn(ctx, item, position, None);
+        }
+        ctx.db
+            .equipment()
+            .identity()
+            .update(equipment_empty(identity));
+    }
+}
+
+// Wears down whatever is equipped in the given slots by one use
+pub fn equipment_wear(ctx: &ReducerContext, identity: Identity, slots: &[DbEquipmentSlot]) {
+    if let Some(mut equipment) = ctx.db.equipment().identity().find(identity) {
+        for slot in slots {
+            if let Some(item) = equipment.slot_mut(*slot) {
+                item_instance_wear(ctx, item);
+            }
+        }
+        ctx.db.equipment().identity().update(equipment);
+    }
+}
+
+fn equipment_get(ctx: &ReducerContext) -> Result<DbEquipment, String> {
+    let equipment = ctx
+        .db
+        .equipment()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Equipment not found")?;
+    Ok(equipment)
+}
+
+// Moves an item from the inventory into its slot, swapping out what was there
+#[spacetimedb::reducer]
+pub fn equipment_equip(ctx: &ReducerContext, slot_index: u32) -> Result<(), String> {
+    let item = inventory_slot_get(ctx, ctx.sender, slot_index)?;
+    let slot = ctx
+        .db
+        .item()
+        .id()
+        .find(item.id)
+        .and_then(|definition| definition.equipment)
+        .ok_or("Item can't be equipped")?
+        .slot;
+    let mut equipment = equipment_get(ctx)?;
+    trade_instance_changed(ctx, ctx.sender, item.instance_id);
+
+    let previous = equipment.slot_mut(slot).replace(item);
+    // Saved first so the inventory sees the new carry weight
+    ctx.db.equipment().identity().update(equipment);
+    inventory_slot_set(ctx, ctx.sender, slot_index, previous)?;
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn equipment_unequip(ctx: &ReducerContext, slot: DbEquipmentSlot) -> Result<(), String> {
+    let mut equipment = equipment_get(ctx)?;
+
+    let item = equipment.slot_mut(slot).take().ok_or("Nothing equipped")?;
+    ctx.db.equipment().identity().update(equipment);
+    inventory_return_many(ctx, ctx.sender, &[item])?;
+    Ok(())
+}
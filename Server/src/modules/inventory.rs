@@ -1,86 +1,760 @@
+use crate::modules::admin::admin_require;
+use crate::modules::equipment::equipment_weight;
+use crate::modules::item::item;
+use crate::modules::item_instance::item_instance_delete;
+use crate::modules::player::{
+    player, player_encumbrance_at, player_set_carry_weight, DbEncumbrance,
+};
+use crate::modules::trade::trade_instance_changed;
+use crate::modules::world_item::world_item_spawn;
+use crate::types::DbVector3;
+use serde::Deserialize;
 use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table};
+use std::collections::BTreeMap;
 
-#[derive(SpacetimeType, Clone, Debug)]
+#[derive(SpacetimeType, Deserialize, Clone, Debug)]
 pub struct ItemRef {
     pub id: u32,
     pub quantity: u32,
+    // Crafted or worn items point at their own durability and stats
+    pub instance_id: Option<u64>,
 }
 
-#[spacetimedb::table(name = item, public)]
-pub struct Item {
-    #[primary_key]
-    pub id: u32,
-    pub name: String,
-    pub description: String,
-    pub weight: f32,
-}
+const INVENTORY_SIZE: u32 = 32;
 
 #[spacetimedb::table(name = inventory, public)]
 pub struct Inventory {
     #[primary_key]
     pub identity: Identity,
     pub size: u32,
-    pub items: Vec<ItemRef>,
+    // Always `size` long, empty slots are None
+    pub slots: Vec<Option<ItemRef>>,
 }
 
-#[spacetimedb::reducer]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InventoryError {
+    InventoryNotFound,
+    ItemNotFound(u32),
+    InvalidQuantity,
+    NotEnoughItems {
+        item_id: u32,
+        needed: u32,
+        held: u32,
+    },
+    InventoryFull,
+    InvalidSlot,
+    SlotEmpty,
+    TooHeavy,
+    QuantityOverflow,
+}
+
+impl std::fmt::Display for InventoryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InventoryError::InventoryNotFound => write!(f, "Inventory not found"),
+            InventoryError::ItemNotFound(item_id) => write!(f, "Item {} not found", item_id),
+            InventoryError::InvalidQuantity => write!(f, "Quantity must be greater than zero"),
+            InventoryError::NotEnoughItems {
+                item_id,
+                needed,
+                held,
+            } => write!(
+                f,
+                "Not enough of item {}, needed {} but only have {}",
+                item_id, needed, held
+            ),
+            InventoryError::InventoryFull => write!(f, "Inventory is full"),
+            InventoryError::InvalidSlot => write!(f, "Invalid inventory slot"),
+            InventoryError::SlotEmpty => write!(f, "Inventory slot is empty"),
+            InventoryError::TooHeavy => write!(f, "Carrying too much weight"),
+            InventoryError::QuantityOverflow => write!(f, "Item quantity is too large"),
+        }
+    }
+}
+
+// Lets reducers returning String errors use `?` on inventory calls
+impl From<InventoryError> for String {
+    fn from(error: InventoryError) -> Self {
+        error.to_string()
+    }
+}
+
+pub fn item_max_stack(ctx: &ReducerContext, item_id: u32) -> Result<u32, InventoryError> {
+    ctx.db
+        .item()
+        .id()
+        .find(item_id)
+        .map(|item| item.max_stack)
+        .ok_or(InventoryError::ItemNotFound(item_id))
+}
+
+// Without an instance only plain stacks count, with one only that exact
+// item does, so the player always picks which instance is used
+pub fn inventory_slots_count(
+    slots: &[Option<ItemRef>],
+    item_id: u32,
+    instance_id: Option<u64>,
+) -> u32 {
+    slots
+        .iter()
+        .flatten()
+        .filter(|item| item.id == item_id && item.instance_id == instance_id)
+        .fold(0u32, |total, item| total.saturating_add(item.quantity))
+}
+
+// Merges entries of the same item, so a list can be checked against
+// what is held in one pass
+fn inventory_merge(items: &[ItemRef]) -> Result<BTreeMap<(u32, Option<u64>), u32>, InventoryError> {
+    let mut merged = BTreeMap::new();
+    for item in items {
+        let total = merged.entry((item.id, item.instance_id)).or_insert(0u32);
+        *total = total
+            .checked_add(item.quantity)
+            .ok_or(InventoryError::QuantityOverflow)?;
+    }
+    merged.retain(|_, quantity| *quantity > 0);
+    Ok(merged)
+}
+
+// Tops up existing stacks first and then fills empty slots, returns
+// the quantity that didn't fit
+fn inventory_slots_stack(
+    slots: &mut [Option<ItemRef>],
+    item_id: u32,
+    quantity: u32,
+    max_stack: u32,
+) -> u32 {
+    let mut remaining = quantity;
+
+    for item in slots.iter_mut().flatten() {
+        if remaining == 0 {
+            break;
+        }
+        if item.id == item_id && item.instance_id.is_none() && item.quantity < max_stack {
+            let added = remaining.min(max_stack - item.quantity);
+            item.quantity += added;
+            remaining -= added;
+        }
+    }
+
+    for slot in slots.iter_mut() {
+        if remaining == 0 {
+            break;
+        }
+        if slot.is_none() {
+            let added = remaining.min(max_stack);
+            *slot = Some(ItemRef {
+                id: item_id,
+                quantity: added,
+                instance_id: None,
+            });
+            remaining -= added;
+        }
+    }
+    remaining
+}
+
+// Stacks the item up to its own stack size
+pub fn inventory_slots_add(
+    ctx: &ReducerContext,
+    slots: &mut [Option<ItemRef>],
+    item_id: u32,
+    quantity: u32,
+) -> Result<u32, InventoryError> {
+    let max_stack = item_max_stack(ctx, item_id)?;
+    Ok(inventory_slots_stack(slots, item_id, quantity, max_stack))
+}
+
+// Takes the quantity out of the last matching stacks first
+pub fn inventory_slots_remove(
+    slots: &mut [Option<ItemRef>],
+    item_id: u32,
+    instance_id: Option<u64>,
+    quantity: u32,
+) -> Result<Vec<ItemRef>, InventoryError> {
+    let held = inventory_slots_count(slots, item_id, instance_id);
+    if held < quantity {
+        return Err(InventoryError::NotEnoughItems {
+            item_id,
+            needed: quantity,
+            held,
+        });
+    }
+
+    // Hands back what was removed so item instances move along with it
+    let mut removed = Vec::new();
+    let mut remaining = quantity;
+    for slot in slots.iter_mut().rev() {
+        if remaining == 0 {
+            break;
+        }
+        if let Some(item) = slot
+            .as_mut()
+            .filter(|item| item.id == item_id && item.instance_id == instance_id)
+        {
+            let taken = remaining.min(item.quantity);
+            item.quantity -= taken;
+            remaining -= taken;
+            removed.push(ItemRef {
+                id: item_id,
+                quantity: taken,
+                instance_id: item.instance_id,
+            });
+            if item.quantity == 0 {
+                *slot = None;
+            }
+        }
+    }
+    Ok(removed)
+}
+
+// Item instances always take a slot of their own, returns the quantity
+// that didn't fit
+fn inventory_slots_add_item(
+    ctx: &ReducerContext,
+    slots: &mut [Option<ItemRef>],
+    item: &ItemRef,
+) -> Result<u32, InventoryError> {
+    if item.instance_id.is_none() {
+        return inventory_slots_add(ctx, slots, item.id, item.quantity);
+    }
+    match slots.iter_mut().find(|slot| slot.is_none()) {
+        Some(slot) => {
+            *slot = Some(item.clone());
+            Ok(0)
+        }
+        None => Ok(item.quantity),
+    }
+}
+
+// Adds every item or none of them
+pub fn inventory_slots_give_many(
+    ctx: &ReducerContext,
+    slots: &mut Vec<Option<ItemRef>>,
+    items: &[ItemRef],
+) -> Result<(), InventoryError> {
+    let mut updated = slots.clone();
+    for item in items {
+        if inventory_slots_add_item(ctx, &mut updated, item)? > 0 {
+            return Err(InventoryError::InventoryFull);
+        }
+    }
+    *slots = updated;
+    Ok(())
+}
+
+// Removes every item or none of them
+pub fn inventory_slots_take_many(
+    slots: &mut Vec<Option<ItemRef>>,
+    items: &[ItemRef],
+) -> Result<Vec<ItemRef>, InventoryError> {
+    let mut updated = slots.clone();
+    let mut removed = Vec::new();
+    for ((item_id, instance_id), quantity) in inventory_merge(items)? {
+        removed.extend(inventory_slots_remove(
+            &mut updated,
+            item_id,
+            instance_id,
+            quantity,
+        )?);
+    }
+    *slots = updated;
+    Ok(removed)
+}
+
+fn inventory_find(ctx: &ReducerContext, identity: Identity) -> Result<Inventory, InventoryError> {
+    ctx.db
+        .inventory()
+        .identity()
+        .find(identity)
+        .ok_or(InventoryError::InventoryNotFound)
+}
+
+pub fn inventory_slots_weight(ctx: &ReducerContext, slots: &[Option<ItemRef>]) -> f32 {
+    slots
+        .iter()
+        .flatten()
+        .map(|slot| {
+            let weight = ctx
+                .db
+                .item()
+                .id()
+                .find(slot.id)
+                .map_or(0.0, |item| item.weight);
+            weight * slot.quantity as f32
+        })
+        .sum()
+}
+
+// Everything the player carries, including what they have equipped
+fn inventory_carried_weight(ctx: &ReducerContext, inventory: &Inventory) -> f32 {
+    inventory_slots_weight(ctx, &inventory.slots) + equipment_weight(ctx, inventory.identity)
+}
+
+// Writes the inventory back and keeps the owner's carry weight in step with it
+fn inventory_save(ctx: &ReducerContext, inventory: Inventory) {
+    let weight = inventory_carried_weight(ctx, &inventory);
+    player_set_carry_weight(ctx, inventory.identity, weight);
+    ctx.db.inventory().identity().update(inventory);
+}
+
+pub fn inventory_can_afford(
+    ctx: &ReducerContext,
+    identity: Identity,
+    items: &[ItemRef],
+) -> Result<(), InventoryError> {
+    let inventory = inventory_find(ctx, identity)?;
+    for ((item_id, instance_id), needed) in inventory_merge(items)? {
+        let held = inventory_slots_count(&inventory.slots, item_id, instance_id);
+        if held < needed {
+            return Err(InventoryError::NotEnoughItems {
+                item_id,
+                needed,
+                held,
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn inventory_take_many(
+    ctx: &ReducerContext,
+    identity: Identity,
+    items: &[ItemRef],
+) -> Result<Vec<ItemRef>, InventoryError> {
+    let mut inventory = inventory_find(ctx, identity)?;
+    let removed = inventory_slots_take_many(&mut inventory.slots, items)?;
+    inventory_save(ctx, inventory);
+    Ok(removed)
+}
+
+// For costs, anything taken is used up for good along with its instances
+pub fn inventory_consume_many(
+    ctx: &ReducerContext,
+    identity: Identity,
+    items: &[ItemRef],
+) -> Result<(), InventoryError> {
+    for item in inventory_take_many(ctx, identity, items)? {
+        if let Some(instance_id) = item.instance_id {
+            item_instance_delete(ctx, instance_id);
+        }
+    }
+    Ok(())
+}
+
+pub fn inventory_give_many(
+    ctx: &ReducerContext,
+    identity: Identity,
+    items: &[ItemRef],
+) -> Result<(), InventoryError> {
+    let mut inventory = inventory_find(ctx, identity)?;
+    let weight = inventory_carried_weight(ctx, &inventory);
+    inventory_slots_give_many(ctx, &mut inventory.slots, items)?;
+
+    // Gains that would leave the player overloaded are refused
+    let new_weight = inventory_carried_weight(ctx, &inventory);
+    if new_weight > weight
+        && player_encumbrance_at(ctx, identity, new_weight) == DbEncumbrance::Overloaded
+    {
+        return Err(InventoryError::TooHeavy);
+    }
+    inventory_save(ctx, inventory);
+    Ok(())
+}
+
+// Puts back items the player was already carrying, like unequipped gear, so
+// their weight counted before and can't push the player over the limit now
+pub fn inventory_return_many(
+    ctx: &ReducerContext,
+    identity: Identity,
+    items: &[ItemRef],
+) -> Result<(), InventoryError> {
+    let mut inventory = inventory_find(ctx, identity)?;
+    inventory_slots_give_many(ctx, &mut inventory.slots, items)?;
+    inventory_save(ctx, inventory);
+    Ok(())
+}
+
+// Not a reducer, items only enter inventories through validated game logic
 pub fn inventory_create(ctx: &ReducerContext) -> Result<(), String> {
+    if inventory_find(ctx, ctx.sender).is_ok() {
+        return Err("Inventory already exists".to_string());
+    }
     let inventory = Inventory {
         identity: ctx.sender,
-        size: 32,
-        items: vec![],
+        size: INVENTORY_SIZE,
+        slots: vec![None; INVENTORY_SIZE as usize],
     };
     ctx.db.inventory().insert(inventory);
     Ok(())
 }
 
-#[spacetimedb::reducer]
-pub fn inventory_add_item(ctx: &ReducerContext, item_id: u32, quantity: u32) -> Result<(), String> {
-    let inventory = ctx.db.inventory().identity().find(ctx.sender);
-    if let Some(mut inventory) = inventory {
-        if let Some(existing_item) = inventory.items.iter_mut().find(|item| item.id == item_id) {
-            existing_item.quantity += quantity;
-        } else {
-            let item_ref = ItemRef {
-                id: item_id,
-                quantity,
+pub fn inventory_add_item(
+    ctx: &ReducerContext,
+    identity: Identity,
+    item_id: u32,
+    quantity: u32,
+) -> Result<(), InventoryError> {
+    if quantity == 0 {
+        return Err(InventoryError::InvalidQuantity);
+    }
+    inventory_give_many(
+        ctx,
+        identity,
+        &[ItemRef {
+            id: item_id,
+            quantity,
+            instance_id: None,
+        }],
+    )
+}
+
+// For refunds and drops that can't be refused, whatever doesn't fit is
+// dropped at the position for the player to pick up, items are never lost
+pub fn inventory_give_or_drop(
+    ctx: &ReducerContext,
+    identity: Identity,
+    items: &[ItemRef],
+    position: &DbVector3,
+) {
+    let Ok(mut inventory) = inventory_find(ctx, identity) else {
+        for item in items {
+            world_item_spawn(ctx, item, position, Some(identity));
+        }
+        return;
+    };
+    for item in items {
+        let overflow =
+            inventory_slots_add_item(ctx, &mut inventory.slots, item).unwrap_or(item.quantity);
+        if overflow > 0 {
+            log::debug!(
+                "Inventory of {} is full, dropping {} of item {}",
+                identity,
+                overflow,
+                item.id
+            );
+            let dropped = ItemRef {
+                quantity: overflow,
+                ..item.clone()
             };
-            inventory.items.push(item_ref);
+            world_item_spawn(ctx, &dropped, position, Some(identity));
         }
-        ctx.db.inventory().identity().update(inventory);
     }
-    Ok(())
+    inventory_save(ctx, inventory);
 }
 
-pub fn inventory_get_item(ctx: &ReducerContext, item_id: u32) -> Result<ItemRef, String> {
-    let inventory = ctx.db.inventory().identity().find(ctx.sender);
-    if let Some(inventory) = inventory {
-        if let Some(item) = inventory.items.iter().find(|item| item.id == item_id) {
-            Ok(item.clone())
-        } else {
-            Err("Item not found in inventory".to_string())
+// Empties the whole inventory onto the ground
+pub fn inventory_drop_all(ctx: &ReducerContext, identity: Identity, position: &DbVector3) {
+    if let Ok(mut inventory) = inventory_find(ctx, identity) {
+        for item in inventory.slots.iter().flatten() {
+            world_item_spawn(ctx, item, position, None);
         }
-    } else {
-        Err("Inventory not found".to_string())
+        inventory.slots.fill(None);
+        inventory_save(ctx, inventory);
     }
 }
 
 #[spacetimedb::reducer]
-pub fn inventory_remove_item(
+pub fn inventory_drop_item(
     ctx: &ReducerContext,
     item_id: u32,
+    instance_id: Option<u64>,
     quantity: u32,
 ) -> Result<(), String> {
-    let inventory = ctx.db.inventory().identity().find(ctx.sender);
-    if let Some(mut inventory) = inventory {
-        if let Some(position) = inventory.items.iter().position(|item| item.id == item_id) {
-            let existing_item = &mut inventory.items[position];
-            existing_item.quantity -= quantity;
-            if existing_item.quantity == 0 {
-                inventory.items.remove(position);
-            }
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    if !player.online {
+        return Err("Player is not online".to_string());
+    }
+    if quantity == 0 {
+        return Err(InventoryError::InvalidQuantity.into());
+    }
+
+    let removed = inventory_take_many(
+        ctx,
+        ctx.sender,
+        &[ItemRef {
+            id: item_id,
+            quantity,
+            instance_id,
+        }],
+    )?;
+    for item in &removed {
+        world_item_spawn(ctx, item, &player.position, None);
+    }
+    Ok(())
+}
+
+pub fn inventory_get_item(ctx: &ReducerContext, item_id: u32) -> Result<ItemRef, InventoryError> {
+    let inventory = inventory_find(ctx, ctx.sender)?;
+    let quantity = inventory_slots_count(&inventory.slots, item_id, None);
+    if quantity == 0 {
+        return Err(InventoryError::ItemNotFound(item_id));
+    }
+    Ok(ItemRef {
+        id: item_id,
+        quantity,
+        instance_id: None,
+    })
+}
+
+pub fn inventory_slot_get(
+    ctx: &ReducerContext,
+    identity: Identity,
+    index: u32,
+) -> Result<ItemRef, InventoryError> {
+    let inventory = inventory_find(ctx, identity)?;
+    inventory
+        .slots
+        .get(index as usize)
+        .ok_or(InventoryError::InvalidSlot)?
+        .clone()
+        .ok_or(InventoryError::SlotEmpty)
+}
+
+// Replaces what is in a slot, for moving items in and out of equipment or
+// attaching an item instance
+pub fn inventory_slot_set(
+    ctx: &ReducerContext,
+    identity: Identity,
+    index: u32,
+    item: Option<ItemRef>,
+) -> Result<(), InventoryError> {
+    let mut inventory = inventory_find(ctx, identity)?;
+    let slot = inventory
+        .slots
+        .get_mut(index as usize)
+        .ok_or(InventoryError::InvalidSlot)?;
+    *slot = item;
+    inventory_save(ctx, inventory);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn inventory_admin_grant(
+    ctx: &ReducerContext,
+    identity: Identity,
+    item_id: u32,
+    quantity: u32,
+) -> Result<(), String> {
+    admin_require(ctx)?;
+    inventory_add_item(ctx, identity, item_id, quantity)?;
+    log::info!(
+        "Admin {} granted {} of item {} to {}",
+        ctx.sender,
+        quantity,
+        item_id,
+        identity
+    );
+    Ok(())
+}
+
+// Moves a stack onto another slot, merging same items up to the stack
+// limit and swapping otherwise
+#[spacetimedb::reducer]
+pub fn inventory_move_slot(ctx: &ReducerContext, from: u32, to: u32) -> Result<(), String> {
+    let mut inventory = inventory_find(ctx, ctx.sender)?;
+    let (from, to) = (from as usize, to as usize);
+    if from >= inventory.slots.len() || to >= inventory.slots.len() {
+        return Err("Invalid inventory slot".to_string());
+    }
+    if from == to {
+        return Ok(());
+    }
+
+    match (inventory.slots[from].clone(), inventory.slots[to].clone()) {
+        // Item instances never stack
+        (Some(source), Some(mut target))
+            if source.id == target.id
+                && source.instance_id.is_none()
+                && target.instance_id.is_none() =>
+        {
+            let max_stack = item_max_stack(ctx, source.id)?;
+            let moved = source
+                .quantity
+                .min(max_stack.saturating_sub(target.quantity));
+            target.quantity += moved;
+            inventory.slots[to] = Some(target);
+            inventory.slots[from] = if source.quantity > moved {
+                Some(ItemRef {
+                    id: source.id,
+                    quantity: source.quantity - moved,
+                    instance_id: None,
+                })
+            } else {
+                None
+            };
         }
-        ctx.db.inventory().identity().update(inventory);
+        _ => inventory.slots.swap(from, to),
     }
+    // Whatever moved may be on offer, the trade partner has to look again
+    for item in [&inventory.slots[from], &inventory.slots[to]]
+        .into_iter()
+        .flatten()
+    {
+        trade_instance_changed(ctx, ctx.sender, item.instance_id);
+    }
+
+    inventory_save(ctx, inventory);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stack(id: u32, quantity: u32, instance_id: Option<u64>) -> Option<ItemRef> {
+        Some(ItemRef {
+            id,
+            quantity,
+            instance_id,
+        })
+    }
+
+    fn contents(slots: &[Option<ItemRef>]) -> Vec<Option<(u32, u32, Option<u64>)>> {
+        slots
+            .iter()
+            .map(|slot| {
+                slot.as_ref()
+                    .map(|item| (item.id, item.quantity, item.instance_id))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn merge_sums_entries_per_item_and_instance() {
+        let items = [
+            stack(1, 3, None).unwrap(),
+            stack(2, 1, Some(7)).unwrap(),
+            stack(1, 4, None).unwrap(),
+            stack(2, 1, Some(8)).unwrap(),
+        ];
+        let merged = inventory_merge(&items).unwrap();
+        assert_eq!(
+            merged.into_iter().collect::<Vec<_>>(),
+            vec![((1, None), 7), ((2, Some(7)), 1), ((2, Some(8)), 1)]
+        );
+    }
+
+    #[test]
+    fn merge_drops_empty_entries() {
+        let merged = inventory_merge(&[stack(1, 0, None).unwrap()]).unwrap();
+        assert!(merged.is_empty());
+    }
+
+    #[test]
+    fn merge_rejects_overflowing_quantities() {
+        let items = [
+            stack(1, u32::MAX, None).unwrap(),
+            stack(1, 1, None).unwrap(),
+        ];
+        assert_eq!(
+            inventory_merge(&items).unwrap_err(),
+            InventoryError::QuantityOverflow
+        );
+    }
+
+    #[test]
+    fn stack_tops_up_existing_stacks_before_empty_slots() {
+        let mut slots = vec![None, stack(1, 8, None), stack(2, 5, None)];
+        let remaining = inventory_slots_stack(&mut slots, 1, 5, 10);
+        assert_eq!(remaining, 0);
+        assert_eq!(
+            contents(&slots),
+            vec![Some((1, 3, None)), Some((1, 10, None)), Some((2, 5, None))]
+        );
+    }
+
+    #[test]
+    fn stack_splits_into_stacks_of_at_most_the_limit() {
+        let mut slots = vec![None, None, None];
+        let remaining = inventory_slots_stack(&mut slots, 1, 25, 10);
+        assert_eq!(remaining, 0);
+        assert_eq!(
+            contents(&slots),
+            vec![Some((1, 10, None)), Some((1, 10, None)), Some((1, 5, None))]
+        );
+    }
+
+    #[test]
+    fn stack_returns_what_does_not_fit() {
+        let mut slots = vec![stack(1, 9, None), None];
+        let remaining = inventory_slots_stack(&mut slots, 1, 15, 10);
+        assert_eq!(remaining, 4);
+        assert_eq!(
+            contents(&slots),
+            vec![Some((1, 10, None)), Some((1, 10, None))]
+        );
+    }
+
+    #[test]
+    fn stack_leaves_item_instances_alone() {
+        let mut slots = vec![stack(1, 1, Some(7)), None];
+        let remaining = inventory_slots_stack(&mut slots, 1, 1, 10);
+        assert_eq!(remaining, 0);
+        assert_eq!(
+            contents(&slots),
+            vec![Some((1, 1, Some(7))), Some((1, 1, None))]
+        );
+    }
+
+    #[test]
+    fn remove_takes_from_the_last_stacks_first() {
+        let mut slots = vec![stack(1, 10, None), stack(2, 1, None), stack(1, 4, None)];
+        let removed = inventory_slots_remove(&mut slots, 1, None, 6).unwrap();
+        assert_eq!(
+            contents(&removed.into_iter().map(Some).collect::<Vec<_>>()),
+            vec![Some((1, 4, None)), Some((1, 2, None))]
+        );
+        assert_eq!(
+            contents(&slots),
+            vec![Some((1, 8, None)), Some((2, 1, None)), None]
+        );
+    }
+
+    #[test]
+    fn remove_only_matches_the_requested_instance() {
+        let mut slots = vec![stack(1, 1, Some(7)), stack(1, 1, Some(8))];
+        let removed = inventory_slots_remove(&mut slots, 1, Some(7), 1).unwrap();
+        assert_eq!(removed[0].instance_id, Some(7));
+        assert_eq!(contents(&slots), vec![None, Some((1, 1, Some(8)))]);
+    }
+
+    #[test]
+    fn remove_fails_without_touching_slots_when_short() {
+        let mut slots = vec![stack(1, 3, None), stack(1, 1, Some(7))];
+        let error = inventory_slots_remove(&mut slots, 1, None, 4).unwrap_err();
+        assert_eq!(
+            error,
+            InventoryError::NotEnoughItems {
+                item_id: 1,
+                needed: 4,
+                held: 3,
+            }
+        );
+        assert_eq!(
+            contents(&slots),
+            vec![Some((1, 3, None)), Some((1, 1, Some(7)))]
+        );
+    }
+
+    #[test]
+    fn take_many_removes_all_items_or_none() {
+        let mut slots = vec![stack(1, 5, None), stack(2, 1, None)];
+        let items = [stack(1, 5, None).unwrap(), stack(2, 2, None).unwrap()];
+        assert!(inventory_slots_take_many(&mut slots, &items).is_err());
+        assert_eq!(
+            contents(&slots),
+            vec![Some((1, 5, None)), Some((2, 1, None))]
+        );
+
+        let items = [stack(1, 2, None).unwrap(), stack(1, 3, None).unwrap()];
+        let removed = inventory_slots_take_many(&mut slots, &items).unwrap();
+        assert_eq!(removed.iter().map(|item| item.quantity).sum::<u32>(), 5);
+        assert_eq!(contents(&slots), vec![None, Some((2, 1, None))]);
+    }
+}
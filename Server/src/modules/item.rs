@@ -0,0 +1,213 @@
+use crate::modules::admin::admin_require;
+use crate::modules::building_container::building_container;
+use crate::modules::building_piece_variant::building_piece_variant;
+use crate::modules::building_station::building_station;
+use crate::modules::catalog::catalog_sync;
+use crate::modules::crafting::crafting_queue;
+use crate::modules::equipment::equipment;
+use crate::modules::inventory::{inventory, ItemRef};
+use crate::modules::item_instance::{item_instance, item_instance_delete};
+use crate::modules::land_claim::land_claim;
+use crate::modules::recipe::recipe;
+use crate::modules::resource_node::resource_node;
+use crate::modules::trade::trade;
+use crate::modules::world_item::world_item;
+use serde::Deserialize;
+use spacetimedb::{ReducerContext, SpacetimeType, Table};
+use std::collections::HashSet;
+
+const ITEMS: &str = include_str!("../../data/items.json");
+
+#[derive(SpacetimeType, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbItemCategory {
+    Resource,
+    Component,
+    Tool,
+    Weapon,
+    Armor,
+    Consumable,
+}
+
+#[derive(SpacetimeType, Deserialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DbItemRarity {
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+    Legendary,
+}
+
+#[derive(SpacetimeType, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbEquipmentSlot {
+    MainHand,
+    OffHand,
+    Head,
+    Chest,
+    Legs,
+    Tool,
+}
+
+// What an item does while it is equipped
+#[derive(SpacetimeType, Deserialize, Clone, Debug)]
+pub struct DbItemEquipment {
+    pub slot: DbEquipmentSlot,
+    pub damage: f32,
+    pub armor: f32,
+    // Multiplies the yield of every harvest
+    pub harvest_power: u32,
+    // Uses before the item breaks and has to be repaired
+    pub max_durability: u32,
+}
+
+#[spacetimedb::table(name = item, public)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct Item {
+    #[primary_key]
+    pub id: u32,
+    pub name: String,
+    pub description: String,
+    pub category: DbItemCategory,
+    pub rarity: DbItemRarity,
+    // Key of the sprite the client shows for this item
+    pub icon_key: String,
+    pub weight: f32,
+    pub max_stack: u32,
+    pub equipment: Option<DbItemEquipment>,
+}
+
+pub fn item_init(ctx: &ReducerContext) -> Result<(), String> {
+    item_load(ctx)
+}
+
+#[spacetimedb::reducer]
+pub fn item_sync(ctx: &ReducerContext) -> Result<(), String> {
+    admin_require(ctx)?;
+    item_load(ctx)
+}
+
+fn item_parse() -> Result<Vec<Item>, String> {
+    let items: Vec<Item> =
+        serde_json::from_str(ITEMS).map_err(|e| format!("Failed to parse items: {}", e))?;
+
+    let mut seen = HashSet::new();
+    for item in &items {
+        if !seen.insert(item.id) {
+            return Err(format!("Duplicate item id {}", item.id));
+        }
+        if item.name.is_empty() || item.icon_key.is_empty() {
+            return Err(format!("Item {} needs a name and an icon key", item.id));
+        }
+        if !item.weight.is_finite() || item.weight < 0.0 {
+            return Err(format!("Item {} has an invalid weight", item.id));
+        }
+        if item.max_stack == 0 {
+            return Err(format!("Item {} must stack to at least one", item.id));
+        }
+        if let Some(equipment) = &item.equipment {
+            if item.max_stack != 1 {
+                return Err(format!("Equippable item {} can't stack", item.id));
+            }
+            let stats_valid = [equipment.damage, equipment.armor]
+                .iter()
+                .all(|stat| stat.is_finite() && *stat >= 0.0);
+            if !stats_valid || equipment.max_durability == 0 {
+                return Err(format!("Item {} has invalid equipment stats", item.id));
+            }
+        }
+    }
+
+    Ok(items)
+}
+
+// Whether anything in the world still refers to the item, such items are
+// kept when they leave the catalog so nothing points at a missing row
+fn item_in_use(ctx: &ReducerContext, id: u32) -> bool {
+    let has = |items: &[ItemRef]| items.iter().any(|item| item.id == id);
+    let holds = |slots: &[Option<ItemRef>]| slots.iter().flatten().any(|item| item.id == id);
+
+    let referenced = ctx.db.building_piece_variant().iter().any(|variant| {
+        variant
+            .tiers
+            .iter()
+            .flat_map(|stats| &stats.build_cost)
+            .any(|cost| cost.item_id == id)
+    }) || ctx.db.recipe().iter().any(|recipe| {
+        has(&recipe.inputs) || has(&recipe.outputs) || recipe.fuel.is_some_and(|fuel| fuel.id == id)
+    }) || ctx.db.resource_node().iter().any(|node| node.item_id == id);
+    if referenced {
+        return true;
+    }
+
+    ctx.db
+        .inventory()
+        .iter()
+        .any(|inventory| holds(&inventory.slots))
+        || ctx
+            .db
+            .building_container()
+            .iter()
+            .any(|container| holds(&container.slots))
+        || ctx
+            .db
+            .building_station()
+            .iter()
+            .any(|station| holds(&station.fuel) || holds(&station.processing))
+        || ctx
+            .db
+            .equipment()
+            .iter()
+            .any(|equipment| equipment.items().any(|item| item.id == id))
+        || ctx.db.world_item().iter().any(|item| item.item_id == id)
+        || ctx.db.land_claim().iter().any(|claim| has(&claim.upkeep))
+        || ctx
+            .db
+            .trade()
+            .iter()
+            .any(|trade| has(&trade.initiator_offer.items) || has(&trade.partner_offer.items))
+        || ctx
+            .db
+            .crafting_queue()
+            .iter()
+            .any(|craft| has(&craft.inputs) || has(&craft.outputs))
+}
+
+// Upserts every item from the catalog and removes items that were dropped
+// from it, as long as nothing still refers to them
+fn item_load(ctx: &ReducerContext) -> Result<(), String> {
+    let current_ids = ctx.db.item().iter().map(|item| item.id).collect();
+    catalog_sync(
+        item_parse()?,
+        current_ids,
+        |item| item.id,
+        |item| {
+            ctx.db.item().insert(item);
+        },
+        |item| {
+            ctx.db.item().id().update(item);
+        },
+        |id| {
+            if item_in_use(ctx, id) {
+                log::warn!(
+                    "Item {} was removed from the catalog but is still in use, keeping it",
+                    id
+                );
+                return;
+            }
+            // Nothing holds them anymore, so their instances are orphaned too
+            let orphaned: Vec<u64> = ctx
+                .db
+                .item_instance()
+                .iter()
+                .filter(|instance| instance.item_id == id)
+                .map(|instance| instance.instance_id)
+                .collect();
+            for instance_id in orphaned {
+                item_instance_delete(ctx, instance_id);
+            }
+            ctx.db.item().id().delete(id);
+        },
+    );
+
+    log::info!("Items synced");
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use crate::modules::building_station::building_station_find_usable;
+use crate::modules::inventory::{
+    inventory_consume_many, inventory_slot_get, inventory_slot_set, ItemRef,
+};
+use crate::modules::item::{item, DbItemEquipment};
+use crate::modules::recipe::recipe;
+use crate::modules::trade::trade_instance_changed;
+use spacetimedb::rand::Rng;
+use spacetimedb::{Identity, ReducerContext, Table};
+
+const ITEM_NAME_MAX_LENGTH: usize = 32;
+// Crafted items roll their stats within this share of the catalog values
+const STAT_ROLL_RANGE: f32 = 0.1;
+
+// Durability and stats of a single equippable item, items straight from the
+// catalog have no row until they are crafted, worn or renamed
+#[spacetimedb::table(name = item_instance, public)]
+pub struct DbItemInstance {
+    #[primary_key]
+    #[auto_inc]
+    pub instance_id: u64,
+    pub item_id: u32,
+    pub durability: u32,
+    pub custom_name: Option<String>,
+    pub crafted_by: Option<Identity>,
+    pub stats: DbItemEquipment,
+}
+
+fn item_instance_catalog_stats(ctx: &ReducerContext, item_id: u32) -> Option<DbItemEquipment> {
+    ctx.db
+        .item()
+        .id()
+        .find(item_id)
+        .and_then(|item| item.equipment)
+}
+
+// Only equippable items get instances
+fn item_instance_create(
+    ctx: &ReducerContext,
+    item_id: u32,
+    crafted_by: Option<Identity>,
+) -> Option<u64> {
+    let mut stats = item_instance_catalog_stats(ctx, item_id)?;
+    if crafted_by.is_some() {
+        stats.damage *= 1.0 + ctx.rng().gen_range(-STAT_ROLL_RANGE..=STAT_ROLL_RANGE);
+        stats.armor *= 1.0 + ctx.rng().gen_range(-STAT_ROLL_RANGE..=STAT_ROLL_RANGE);
+    }
+
+    let instance = ctx.db.item_instance().insert(DbItemInstance {
+        instance_id: 0,
+        item_id,
+        durability: stats.max_durability,
+        custom_name: None,
+        crafted_by,
+        stats,
+    });
+    Some(instance.instance_id)
+}
+
+pub fn item_instance_delete(ctx: &ReducerContext, instance_id: u64) {
+    ctx.db.item_instance().instance_id().delete(instance_id);
+}
+
+// Stats the item gives while equipped, broken items give none
+pub fn item_instance_stats(ctx: &ReducerContext, item: &ItemRef) -> Option<DbItemEquipment> {
+    match item.instance_id {
+        Some(instance_id) => ctx
+            .db
+            .item_instance()
+            .instance_id()
+            .find(instance_id)
+            .filter(|instance| instance.durability > 0)
+            .map(|instance| instance.stats),
+        None => item_instance_catalog_stats(ctx, item.id),
+    }
+}
+
+// Wears the item down by one use, giving it an instance on first use
+pub fn item_instance_wear(ctx: &ReducerContext, item: &mut ItemRef) {
+    if item.instance_id.is_none() {
+        item.instance_id = item_instance_create(ctx, item.id, None);
+    }
+    let Some(mut instance) = item
+        .instance_id
+        .and_then(|instance_id| ctx.db.item_instance().instance_id().find(instance_id))
+    else {
+        return;
+    };
+
+    if instance.durability > 0 {
+        instance.durability -= 1;
+        if instance.durability == 0 {
+            log::debug!("Item instance {} broke", instance.instance_id);
+        }
+        ctx.db.item_instance().instance_id().update(instance);
+    }
+}
+
+// Gives every equippable output of a finished craft its own rolled instance
+pub fn item_instance_craft(
+    ctx: &ReducerContext,
+    outputs: &[ItemRef],
+    crafter: Identity,
+) -> Vec<ItemRef> {
+    let mut crafted = Vec::new();
+    for output in outputs {
+        if item_instance_catalog_stats(ctx, output.id).is_none() {
+            crafted.push(output.clone());
+            continue;
+        }
+        for _ in 0..output.quantity {
+            crafted.push(ItemRef {
+                id: output.id,
+                quantity: 1,
+                instance_id: item_instance_create(ctx, output.id, Some(crafter)),
+            });
+        }
+    }
+    crafted
+}
+
+#[spacetimedb::reducer]
+pub fn item_instance_rename(
+    ctx: &ReducerContext,
+    slot_index: u32,
+    name: Option<String>,
+) -> Result<(), String> {
+    if name
+        .as_ref()
+        .is_some_and(|name| name.is_empty() || name.len() > ITEM_NAME_MAX_LENGTH)
+    {
+        return Err("Invalid item name".to_string());
+    }
+
+    let mut item = inventory_slot_get(ctx, ctx.sender, slot_index)?;
+    if item.instance_id.is_none() {
+        item.instance_id =
+            Some(item_instance_create(ctx, item.id, None).ok_or("Item can't be renamed")?);
+        inventory_slot_set(ctx, ctx.sender, slot_index, Some(item.clone()))?;
+    }
+    let mut instance = item
+        .instance_id
+        .and_then(|instance_id| ctx.db.item_instance().instance_id().find(instance_id))
+        .ok_or("Item can't be renamed")?;
+
+    instance.custom_name = name;
+    trade_instance_changed(ctx, ctx.sender, Some(instance.instance_id));
+    ctx.db.item_instance().instance_id().update(instance);
+    Ok(())
+}
+
+// Repairs at the station the item is crafted at, costing the share of the
+// recipe inputs that matches the durability lost
+#[spacetimedb::reducer]
+pub fn item_instance_repair(ctx: &ReducerContext, slot_index: u32) -> Result<(), String> {
+    let item = inventory_slot_get(ctx, ctx.sender, slot_index)?;
+    let mut instance = item
+        .instance_id
+        .and_then(|instance_id| ctx.db.item_instance().instance_id().find(instance_id))
+        .ok_or("Item is not damaged")?;
+    let max_durability = instance.stats.max_durability;
+    let missing = max_durability.saturating_sub(instance.durability);
+    if missing == 0 {
+        return Err("Item is not damaged".to_string());
+    }
+
+    let recipe = ctx
+        .db
+        .recipe()
+        .iter()
+        .find(|recipe| recipe.outputs.iter().any(|output| output.id == item.id))
+        .ok_or("Item can't be repaired")?;
+    if let Some(variant_id) = recipe.station_variant_id {
+        building_station_find_usable(ctx, variant_id)?;
+    }
+
+    let cost: Vec<ItemRef> = recipe
+        .inputs
+        .iter()
+        .map(|input| ItemRef {
+            id: input.id,
+            quantity: (input.quantity as u64 * missing as u64).div_ceil(max_durability as u64)
+                as u32,
+            instance_id: None,
+        })
+        .collect();
+    inventory_consume_many(ctx, ctx.sender, &cost)?;
+
+    instance.durability = max_durability;
+    trade_instance_changed(ctx, ctx.sender, Some(instance.instance_id));
+    ctx.db.item_instance().instance_id().update(instance);
+    Ok(())
+}
@@ -0,0 +1,260 @@
+use crate::modules::building_chunk::building_chunk_pieces_in_column;
+use crate::modules::building_piece_placed::DbBuildingPiecePlaced;
+use crate::modules::inventory::{
+    inventory_consume_many, inventory_give_or_drop, InventoryError, ItemRef,
+};
+use crate::types::DbVector3;
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table};
+
+// Horizontal radius around a claim piece that its owner controls
+pub const CLAIM_RADIUS: f32 = 30.0;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbLandClaimAccess {
+    Build,
+    Remove,
+    Access,
+}
+
+#[spacetimedb::table(name = land_claim, public)]
+pub struct DbLandClaim {
+    // Same id as the claim piece that established it
+    #[primary_key]
+    pub claim_id: u32,
+    #[index(btree)]
+    pub owner: Identity,
+    pub position: DbVector3,
+    pub radius: f32,
+    // Materials the claim pays its upkeep from
+    pub upkeep: Vec<ItemRef>,
+    pub upkeep_paid: bool,
+}
+
+#[spacetimedb::table(name = land_claim_permission, public)]
+pub struct DbLandClaimPermission {
+    #[primary_key]
+    #[auto_inc]
+    pub permission_id: u64,
+    #[index(btree)]
+    pub claim_id: u32,
+    pub identity: Identity,
+    pub can_build: bool,
+    pub can_remove: bool,
+    pub can_access: bool,
+}
+
+// Claims reach from the ground to the sky, so only the x/z distance counts
+pub fn land_claim_covers(claim: &DbLandClaim, position: &DbVector3) -> bool {
+    claim.position.horizontal_distance(position) <= claim.radius
+}
+
+// Claims of one owner can overlap, the one with its upkeep paid wins so a
+// lapsed claim never hides a paid one
+pub fn land_claim_at(ctx: &ReducerContext, position: &DbVector3) -> Option<DbLandClaim> {
+    ctx.db
+        .land_claim()
+        .iter()
+        .filter(|claim| land_claim_covers(claim, position))
+        .max_by_key(|claim| claim.upkeep_paid)
+}
+
+fn land_claim_permission_get(
+    ctx: &ReducerContext,
+    claim_id: u32,
+    identity: Identity,
+) -> Option<DbLandClaimPermission> {
+    ctx.db
+        .land_claim_permission()
+        .claim_id()
+        .filter(claim_id)
+        .find(|permission| permission.identity == identity)
+}
+
+pub fn land_claim_has_access(
+    ctx: &ReducerContext,
+    claim: &DbLandClaim,
+    identity: Identity,
+    access: DbLandClaimAccess,
+) -> bool {
+    if claim.owner == identity {
+        return true;
+    }
+
+    match land_claim_permission_get(ctx, claim.claim_id, identity) {
+        Some(permission) => match access {
+            DbLandClaimAccess::Build => permission.can_build,
+            DbLandClaimAccess::Remove => permission.can_remove,
+            DbLandClaimAccess::Access => permission.can_access,
+        },
+        None => false,
+    }
+}
+
+// Rejects the action if the position is inside a claim the sender has no rights in
+pub fn land_claim_check(
+    ctx: &ReducerContext,
+    position: &DbVector3,
+    access: DbLandClaimAccess,
+) -> Result<(), String> {
+    match land_claim_at(ctx, position) {
+        Some(claim) if !land_claim_has_access(ctx, &claim, ctx.sender, access) => {
+            Err("This area is claimed by another player".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn land_claim_create(
+    ctx: &ReducerContext,
+    piece: &DbBuildingPiecePlaced,
+) -> Result<(), String> {
+    // Claims can't reach into someone else's claim
+    let overlapping = ctx.db.land_claim().iter().any(|claim| {
+        claim.owner != piece.owner
+            && claim.position.horizontal_distance(&piece.position) < claim.radius + CLAIM_RADIUS
+    });
+    if overlapping {
+        return Err("Claim overlaps another player's claim".to_string());
+    }
+
+    // Nor over pieces other players already built, which the claim owner could then tear down
+    let covers_others = building_chunk_pieces_in_column(ctx, &piece.position, CLAIM_RADIUS)
+        .iter()
+        .any(|other| other.owner != piece.owner);
+    if covers_others {
+        return Err("Claim covers another player's building pieces".to_string());
+    }
+
+    ctx.db.land_claim().insert(DbLandClaim {
+        claim_id: piece.piece_id,
+        owner: piece.owner,
+        position: piece.position.clone(),
+        radius: CLAIM_RADIUS,
+        upkeep: vec![],
+        upkeep_paid: true,
+    });
+    Ok(())
+}
+
+pub fn land_claim_delete(ctx: &ReducerContext, claim_id: u32) -> Result<(), String> {
+    if let Some(claim) = ctx.db.land_claim().claim_id().find(claim_id) {
+        // Hand any leftover upkeep back to the owner
+        inventory_give_or_drop(ctx, claim.owner, &claim.upkeep, &claim.position);
+        ctx.db.land_claim_permission().claim_id().delete(claim_id);
+        ctx.db.land_claim().claim_id().delete(claim_id);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn land_claim_deposit_upkeep(
+    ctx: &ReducerContext,
+    claim_id: u32,
+    item_id: u32,
+    quantity: u32,
+) -> Result<(), String> {
+    let mut claim = ctx
+        .db
+        .land_claim()
+        .claim_id()
+        .find(claim_id)
+        .ok_or("Land claim not found")?;
+    if !land_claim_has_access(ctx, &claim, ctx.sender, DbLandClaimAccess::Access) {
+        return Err("No access to this claim".to_string());
+    }
+
+    if quantity == 0 {
+        return Err(InventoryError::InvalidQuantity.into());
+    }
+    inventory_consume_many(
+        ctx,
+        ctx.sender,
+        &[ItemRef {
+            id: item_id,
+            quantity,
+            instance_id: None,
+        }],
+    )?;
+
+    if let Some(existing_item) = claim.upkeep.iter_mut().find(|item| item.id == item_id) {
+        existing_item.quantity = existing_item
+            .quantity
+            .checked_add(quantity)
+            .ok_or(InventoryError::QuantityOverflow)?;
+    } else {
+        claim.upkeep.push(ItemRef {
+            id: item_id,
+            quantity,
+            instance_id: None,
+        });
+    }
+    ctx.db.land_claim().claim_id().update(claim);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn land_claim_grant(
+    ctx: &ReducerContext,
+    claim_id: u32,
+    identity: Identity,
+    can_build: bool,
+    can_remove: bool,
+    can_access: bool,
+) -> Result<(), String> {
+    let claim = ctx
+        .db
+        .land_claim()
+        .claim_id()
+        .find(claim_id)
+        .ok_or("Land claim not found")?;
+    if claim.owner != ctx.sender {
+        return Err("Only the owner can change claim permissions".to_string());
+    }
+
+    if let Some(mut permission) = land_claim_permission_get(ctx, claim_id, identity) {
+        permission.can_build = can_build;
+        permission.can_remove = can_remove;
+        permission.can_access = can_access;
+        ctx.db
+            .land_claim_permission()
+            .permission_id()
+            .update(permission);
+    } else {
+        ctx.db
+            .land_claim_permission()
+            .insert(DbLandClaimPermission {
+                permission_id: 0,
+                claim_id,
+                identity,
+                can_build,
+                can_remove,
+                can_access,
+            });
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn land_claim_revoke(
+    ctx: &ReducerContext,
+    claim_id: u32,
+    identity: Identity,
+) -> Result<(), String> {
+    let claim = ctx
+        .db
+        .land_claim()
+        .claim_id()
+        .find(claim_id)
+        .ok_or("Land claim not found")?;
+    if claim.owner != ctx.sender {
+        return Err("Only the owner can change claim permissions".to_string());
+    }
+
+    if let Some(permission) = land_claim_permission_get(ctx, claim_id, identity) {
+        ctx.db
+            .land_claim_permission()
+            .permission_id()
+            .delete(permission.permission_id);
+    }
+    Ok(())
+}
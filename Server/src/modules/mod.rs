@@ -1,6 +1,27 @@
+pub mod admin;
+pub mod blueprint;
+pub mod building_action_log;
+pub mod building_chunk;
+pub mod building_container;
+pub mod building_decay;
+pub mod building_geometry;
 pub mod building_piece_placed;
+pub mod building_piece_state;
 pub mod building_piece_variant;
+pub mod building_stability;
+pub mod building_station;
+pub mod building_storage;
+pub mod catalog;
+pub mod crafting;
 pub mod creative_camera;
+pub mod equipment;
 pub mod inventory;
+pub mod item;
+pub mod item_instance;
+pub mod land_claim;
 pub mod player;
+pub mod recipe;
+pub mod resource_node;
+pub mod trade;
+pub mod world_item;
 pub mod world_spawn;
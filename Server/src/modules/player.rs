@@ -1,8 +1,13 @@
 use crate::modules::creative_camera::{creative_camera_create, creative_camera_set_enabled};
-use crate::modules::inventory::inventory_create;
+use crate::modules::equipment::{
+    equipment_create, equipment_damage, equipment_drop_all, equipment_reduce_damage, equipment_wear,
+};
+use crate::modules::inventory::{inventory_create, inventory_drop_all};
+use crate::modules::item::DbEquipmentSlot;
 use crate::modules::world_spawn::world_spawn;
 use crate::types::{DbVector2, DbVector3};
-use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table};
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+use std::time::Duration;
 
 #[derive(SpacetimeType, Clone, Debug)]
 pub struct DbAnimationState {
@@ -15,6 +20,29 @@ pub struct DbAnimationState {
     pub is_attacking: bool,
 }
 
+// How close an attacker has to stand to what they hit
+pub const ATTACK_RANGE: f32 = 4.0;
+// Shortest time between two attacks of the same player
+const ATTACK_INTERVAL: Duration = Duration::from_millis(500);
+
+const DEFAULT_MAX_CARRY_WEIGHT: f32 = 150.0;
+// Past this share of the max carry weight the player is overloaded
+const OVERLOAD_FACTOR: f32 = 1.5;
+// Horizontal speed in meters per second a burdened player can't move past
+const ENCUMBERED_MAX_SPEED: f32 = 4.0;
+const OVERLOADED_MAX_SPEED: f32 = 1.5;
+// Longest gap between two moves counted towards the next one, so standing
+// still doesn't build up distance to cover in a single update
+const MAX_MOVE_INTERVAL: Duration = Duration::from_secs(1);
+
+// Caps the movement speed on the server and in the client
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbEncumbrance {
+    Unburdened,
+    Encumbered,
+    Overloaded,
+}
+
 #[spacetimedb::table(name = player, public)]
 pub struct Player {
     #[primary_key]
@@ -30,10 +58,30 @@ pub struct Player {
     pub animation_state: DbAnimationState,
     pub health: f32,
     pub max_health: f32,
+    pub last_seen: Timestamp,
+    pub carry_weight: f32,
+    pub max_carry_weight: f32,
+    pub encumbrance: DbEncumbrance,
+}
+
+// Kept private, only used to rate limit attacks
+#[spacetimedb::table(name = player_attack)]
+pub struct DbPlayerAttack {
+    #[primary_key]
+    pub identity: Identity,
+    pub last_attack_at: Timestamp,
+}
+
+// Kept private, only used to cap the movement speed
+#[spacetimedb::table(name = player_movement)]
+pub struct DbPlayerMovement {
+    #[primary_key]
+    pub identity: Identity,
+    pub last_moved_at: Timestamp,
 }
 
 pub fn player_create(ctx: &ReducerContext) -> Result<(), String> {
-    let (position, rotation) = if let Some(spawn) = ctx.db.world_spawn().id().find(&0) {
+    let (position, rotation) = if let Some(spawn) = ctx.db.world_spawn().id().find(0) {
         (spawn.position, spawn.rotation)
     } else {
         (
@@ -68,6 +116,10 @@ pub fn player_create(ctx: &ReducerContext) -> Result<(), String> {
         },
         health: 100.0,
         max_health: 100.0,
+        last_seen: ctx.timestamp,
+        carry_weight: 0.0,
+        max_carry_weight: DEFAULT_MAX_CARRY_WEIGHT,
+        encumbrance: DbEncumbrance::Unburdened,
     });
 
     log::debug!("Player {} created", ctx.sender);
@@ -84,11 +136,118 @@ pub fn player_set_online_status(ctx: &ReducerContext, online: bool) -> Result<()
         }
 
         player.online = online;
+        player.last_seen = ctx.timestamp;
         ctx.db.player().identity().update(player);
     }
     Ok(())
 }
 
+fn player_encumbrance(player: &Player, weight: f32) -> DbEncumbrance {
+    if weight > player.max_carry_weight * OVERLOAD_FACTOR {
+        DbEncumbrance::Overloaded
+    } else if weight > player.max_carry_weight {
+        DbEncumbrance::Encumbered
+    } else {
+        DbEncumbrance::Unburdened
+    }
+}
+
+// Encumbrance the player would have when carrying the given weight
+pub fn player_encumbrance_at(
+    ctx: &ReducerContext,
+    identity: Identity,
+    weight: f32,
+) -> DbEncumbrance {
+    ctx.db
+        .player()
+        .identity()
+        .find(identity)
+        .map_or(DbEncumbrance::Unburdened, |player| {
+            player_encumbrance(&player, weight)
+        })
+}
+
+pub fn player_set_carry_weight(ctx: &ReducerContext, identity: Identity, weight: f32) {
+    if let Some(mut player) = ctx.db.player().identity().find(identity) {
+        let encumbrance = player_encumbrance(&player, weight);
+        if player.carry_weight == weight && player.encumbrance == encumbrance {
+            return;
+        }
+        player.encumbrance = encumbrance;
+        player.carry_weight = weight;
+        ctx.db.player().identity().update(player);
+    }
+}
+
+fn player_max_speed(encumbrance: DbEncumbrance) -> Option<f32> {
+    match encumbrance {
+        DbEncumbrance::Unburdened => None,
+        DbEncumbrance::Encumbered => Some(ENCUMBERED_MAX_SPEED),
+        DbEncumbrance::Overloaded => Some(OVERLOADED_MAX_SPEED),
+    }
+}
+
+// Shortens the horizontal part of a move to the given distance, height is
+// left alone so falling isn't slowed down
+fn player_limit_step(from: &DbVector3, to: DbVector3, max_distance: f32) -> DbVector3 {
+    let distance = from.horizontal_distance(&to);
+    if distance <= max_distance {
+        return to;
+    }
+    let scale = max_distance / distance;
+    DbVector3 {
+        x: from.x + (to.x - from.x) * scale,
+        y: to.y,
+        z: from.z + (to.z - from.z) * scale,
+    }
+}
+
+// Rejects the action unless the sender is online and within range of the position
+pub fn player_require_in_range(
+    ctx: &ReducerContext,
+    position: &DbVector3,
+    range: f32,
+) -> Result<(), String> {
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(ctx.sender)
+        .ok_or("Player not found")?;
+    if !player.online {
+        return Err("Player is not online".to_string());
+    }
+    let distance = player.position.distance(position);
+    if distance.is_nan() || distance > range {
+        return Err("Too far away".to_string());
+    }
+    Ok(())
+}
+
+// Rejects attacks that come in faster than the swing interval
+pub fn player_require_attack_ready(ctx: &ReducerContext) -> Result<(), String> {
+    match ctx.db.player_attack().identity().find(ctx.sender) {
+        Some(mut attack) => {
+            let ready = ctx
+                .timestamp
+                .duration_since(attack.last_attack_at)
+                .is_some_and(|elapsed| elapsed >= ATTACK_INTERVAL);
+            if !ready {
+                return Err("Attacking too fast".to_string());
+            }
+            attack.last_attack_at = ctx.timestamp;
+            ctx.db.player_attack().identity().update(attack);
+        }
+        None => {
+            ctx.db.player_attack().insert(DbPlayerAttack {
+                identity: ctx.sender,
+                last_attack_at: ctx.timestamp,
+            });
+        }
+    }
+    Ok(())
+}
+
 #[spacetimedb::reducer]
 pub fn player_connected(ctx: &ReducerContext) -> Result<(), String> {
     if ctx.db.player().identity().find(ctx.sender).is_some() {
@@ -99,6 +258,7 @@ pub fn player_connected(ctx: &ReducerContext) -> Result<(), String> {
         creative_camera_create(ctx)?;
         inventory_create(ctx)?;
     }
+    equipment_create(ctx, ctx.sender);
     Ok(())
 }
 
@@ -121,9 +281,37 @@ pub fn player_update(
 
 #[spacetimedb::reducer]
 pub fn player_set_position(ctx: &ReducerContext, position: DbVector3) -> Result<(), String> {
+    if !position.is_finite() {
+        return Err("Invalid position".to_string());
+    }
     if let Some(mut player) = ctx.db.player().identity().find(ctx.sender) {
-        player.position = position;
+        let last_moved_at = ctx
+            .db
+            .player_movement()
+            .identity()
+            .find(ctx.sender)
+            .map(|movement| movement.last_moved_at);
+        let elapsed = last_moved_at
+            .and_then(|last| ctx.timestamp.duration_since(last))
+            .map_or(MAX_MOVE_INTERVAL, |elapsed| elapsed.min(MAX_MOVE_INTERVAL));
+
+        player.position = match player_max_speed(player.encumbrance) {
+            Some(speed) => {
+                player_limit_step(&player.position, position, speed * elapsed.as_secs_f32())
+            }
+            None => position,
+        };
         ctx.db.player().identity().update(player);
+
+        let movement = DbPlayerMovement {
+            identity: ctx.sender,
+            last_moved_at: ctx.timestamp,
+        };
+        if last_moved_at.is_some() {
+            ctx.db.player_movement().identity().update(movement);
+        } else {
+            ctx.db.player_movement().insert(movement);
+        }
         Ok(())
     } else {
         Err("Player not found".to_string())
@@ -156,24 +344,43 @@ pub fn player_set_animation_state(
 }
 
 #[spacetimedb::reducer]
-pub fn player_apply_damage(
-    ctx: &ReducerContext,
-    target_identity: Identity,
-    damage: f32,
-) -> Result<(), String> {
+pub fn player_apply_damage(ctx: &ReducerContext, target_identity: Identity) -> Result<(), String> {
     // Validate that the attacker exists and is online
-    if let Some(attacker) = ctx.db.player().identity().find(&ctx.sender) {
+    if let Some(attacker) = ctx.db.player().identity().find(ctx.sender) {
         if !attacker.online {
             return Err("Attacker is not online".to_string());
         }
 
-        // Apply damage to target
-        if let Some(mut target) = ctx.db.player().identity().find(&target_identity) {
+        // Apply damage to target, both derived from what is equipped
+        if let Some(mut target) = ctx.db.player().identity().find(target_identity) {
+            player_require_in_range(ctx, &target.position, ATTACK_RANGE)?;
+            player_require_attack_ready(ctx)?;
+            let damage =
+                equipment_reduce_damage(ctx, target_identity, equipment_damage(ctx, ctx.sender));
+            equipment_wear(ctx, ctx.sender, &[DbEquipmentSlot::MainHand]);
+            equipment_wear(
+                ctx,
+                target_identity,
+                &[
+                    DbEquipmentSlot::OffHand,
+                    DbEquipmentSlot::Head,
+                    DbEquipmentSlot::Chest,
+                    DbEquipmentSlot::Legs,
+                ],
+            );
+            let was_alive = target.health > 0.0;
             target.health -= damage;
             if target.health < 0.0 {
                 target.health = 0.0;
             }
+            let died = was_alive && target.health == 0.0;
+            let position = target.position.clone();
             ctx.db.player().identity().update(target);
+            // A player that just died drops everything they carried
+            if died {
+                equipment_drop_all(ctx, target_identity, &position);
+                inventory_drop_all(ctx, target_identity, &position);
+            }
             Ok(())
         } else {
             Err("Target player not found".to_string())
@@ -185,7 +392,7 @@ pub fn player_apply_damage(
 
 #[spacetimedb::reducer]
 pub fn player_reset_health(ctx: &ReducerContext, target_identity: Identity) -> Result<(), String> {
-    if let Some(mut player) = ctx.db.player().identity().find(&target_identity) {
+    if let Some(mut player) = ctx.db.player().identity().find(target_identity) {
         player.health = player.max_health;
         ctx.db.player().identity().update(player);
         Ok(())
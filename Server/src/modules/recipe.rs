@@ -0,0 +1,134 @@
+use crate::modules::admin::admin_require;
+use crate::modules::building_piece_variant::building_piece_variant;
+use crate::modules::catalog::catalog_sync;
+use crate::modules::inventory::ItemRef;
+use crate::modules::item::item;
+use serde::Deserialize;
+use spacetimedb::{ReducerContext, Table};
+use std::collections::HashSet;
+
+const RECIPES: &str = include_str!("../../data/recipes.json");
+
+#[spacetimedb::table(name = recipe, public)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct DbRecipe {
+    #[primary_key]
+    pub recipe_id: u32,
+    pub name: String,
+    pub inputs: Vec<ItemRef>,
+    pub outputs: Vec<ItemRef>,
+    pub craft_seconds: u32,
+    // Building piece variant the player has to stand next to, if any
+    pub station_variant_id: Option<u32>,
+    // Burned from the station's fuel slots per craft
+    pub fuel: Option<ItemRef>,
+}
+
+pub fn recipe_get(ctx: &ReducerContext, recipe_id: u32) -> Result<DbRecipe, String> {
+    let recipe = ctx
+        .db
+        .recipe()
+        .recipe_id()
+        .find(recipe_id)
+        .ok_or("Recipe not found")?;
+
+    Ok(recipe)
+}
+
+pub fn recipe_init(ctx: &ReducerContext) -> Result<(), String> {
+    recipe_load(ctx)
+}
+
+#[spacetimedb::reducer]
+pub fn recipe_sync(ctx: &ReducerContext) -> Result<(), String> {
+    admin_require(ctx)?;
+    recipe_load(ctx)
+}
+
+fn recipe_parse(ctx: &ReducerContext) -> Result<Vec<DbRecipe>, String> {
+    let recipes: Vec<DbRecipe> =
+        serde_json::from_str(RECIPES).map_err(|e| format!("Failed to parse recipes: {}", e))?;
+
+    let known_items: HashSet<u32> = ctx.db.item().iter().map(|item| item.id).collect();
+    let mut seen = HashSet::new();
+    for recipe in &recipes {
+        if !seen.insert(recipe.recipe_id) {
+            return Err(format!("Duplicate recipe id {}", recipe.recipe_id));
+        }
+        if recipe.inputs.is_empty() || recipe.outputs.is_empty() {
+            return Err(format!(
+                "Recipe {} needs inputs and outputs",
+                recipe.recipe_id
+            ));
+        }
+        for item in recipe.inputs.iter().chain(&recipe.outputs) {
+            if !known_items.contains(&item.id) {
+                return Err(format!(
+                    "Recipe {} uses unknown item {}",
+                    recipe.recipe_id, item.id
+                ));
+            }
+            if item.quantity == 0 {
+                return Err(format!(
+                    "Recipe {} has a zero quantity item",
+                    recipe.recipe_id
+                ));
+            }
+        }
+        let station = match recipe.station_variant_id {
+            Some(variant_id) => Some(
+                ctx.db
+                    .building_piece_variant()
+                    .variant_id()
+                    .find(variant_id)
+                    .and_then(|variant| variant.station)
+                    .ok_or(format!(
+                        "Recipe {} needs unknown station {}",
+                        recipe.recipe_id, variant_id
+                    ))?,
+            ),
+            None => None,
+        };
+        if let Some(fuel) = &recipe.fuel {
+            if station.is_none_or(|station| station.fuel_slots == 0) {
+                return Err(format!(
+                    "Recipe {} burns fuel but its station has no fuel slots",
+                    recipe.recipe_id
+                ));
+            }
+            if !known_items.contains(&fuel.id) || fuel.quantity == 0 {
+                return Err(format!("Recipe {} has invalid fuel", recipe.recipe_id));
+            }
+        }
+    }
+
+    Ok(recipes)
+}
+
+// Replaces the recipe table with the catalog, crafts already queued keep
+// the inputs they were started with
+fn recipe_load(ctx: &ReducerContext) -> Result<(), String> {
+    let current_ids = ctx
+        .db
+        .recipe()
+        .iter()
+        .map(|recipe| recipe.recipe_id)
+        .collect();
+    catalog_sync(
+        recipe_parse(ctx)?,
+        current_ids,
+        |recipe| recipe.recipe_id,
+        |recipe| {
+            ctx.db.recipe().insert(recipe);
+        },
+        |recipe| {
+            ctx.db.recipe().recipe_id().update(recipe);
+        },
+        |recipe_id| {
+            ctx.db.recipe().recipe_id().delete(recipe_id);
+        },
+    );
+
+    log::info!("Recipes synced");
+    Ok(())
+}
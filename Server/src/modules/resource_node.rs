@@ -0,0 +1,151 @@
+use crate::modules::admin::admin_require;
+use crate::modules::equipment::{equipment_harvest_power, equipment_wear};
+use crate::modules::inventory::{inventory_give_many, ItemRef};
+use crate::modules::item::{item, DbEquipmentSlot};
+use crate::modules::player::player_require_in_range;
+use crate::types::DbVector3;
+use spacetimedb::{ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+use std::time::Duration;
+
+const RESPAWN_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+// How close a player has to stand to gather from a node
+const HARVEST_RANGE: f32 = 3.0;
+
+#[spacetimedb::table(name = resource_node, public)]
+pub struct DbResourceNode {
+    #[primary_key]
+    #[auto_inc]
+    pub node_id: u32,
+    pub position: DbVector3,
+    pub item_id: u32,
+    // Granted per harvest, the last harvest gets whatever is left
+    pub yield_per_harvest: u32,
+    pub capacity: u32,
+    pub remaining: u32,
+    pub respawn_seconds: u32,
+    // Set while the node is depleted
+    pub respawn_at: Option<Timestamp>,
+}
+
+#[spacetimedb::table(name = resource_node_respawn_schedule, scheduled(resource_node_respawn_tick))]
+pub struct ResourceNodeRespawnSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+pub fn resource_node_init(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db
+        .resource_node_respawn_schedule()
+        .insert(ResourceNodeRespawnSchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from(RESPAWN_CHECK_INTERVAL)),
+        });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn resource_node_spawn(
+    ctx: &ReducerContext,
+    position: DbVector3,
+    item_id: u32,
+    yield_per_harvest: u32,
+    capacity: u32,
+    respawn_seconds: u32,
+) -> Result<(), String> {
+    admin_require(ctx)?;
+
+    if ctx.db.item().id().find(item_id).is_none() {
+        return Err("Item not found".to_string());
+    }
+    if yield_per_harvest == 0 || capacity == 0 {
+        return Err("Resource node must yield something".to_string());
+    }
+
+    ctx.db.resource_node().insert(DbResourceNode {
+        node_id: 0,
+        position,
+        item_id,
+        yield_per_harvest,
+        capacity,
+        remaining: capacity,
+        respawn_seconds,
+        respawn_at: None,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn resource_node_remove(ctx: &ReducerContext, node_id: u32) -> Result<(), String> {
+    admin_require(ctx)?;
+    if !ctx.db.resource_node().node_id().delete(node_id) {
+        return Err("Resource node not found".to_string());
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn resource_node_harvest(ctx: &ReducerContext, node_id: u32) -> Result<(), String> {
+    let mut node = ctx
+        .db
+        .resource_node()
+        .node_id()
+        .find(node_id)
+        .ok_or("Resource node not found")?;
+    player_require_in_range(ctx, &node.position, HARVEST_RANGE)?;
+
+    if node.remaining == 0 {
+        return Err("Resource node is depleted".to_string());
+    }
+
+    // Better tools gather more per harvest
+    let quantity = node
+        .yield_per_harvest
+        .saturating_mul(equipment_harvest_power(ctx, ctx.sender))
+        .min(node.remaining);
+    inventory_give_many(
+        ctx,
+        ctx.sender,
+        &[ItemRef {
+            id: node.item_id,
+            quantity,
+            instance_id: None,
+        }],
+    )?;
+
+    equipment_wear(ctx, ctx.sender, &[DbEquipmentSlot::Tool]);
+
+    node.remaining -= quantity;
+    if node.remaining == 0 {
+        node.respawn_at = Some(ctx.timestamp + Duration::from_secs(node.respawn_seconds as u64));
+    }
+    ctx.db.resource_node().node_id().update(node);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn resource_node_respawn_tick(
+    ctx: &ReducerContext,
+    _schedule: ResourceNodeRespawnSchedule,
+) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("Resource nodes can only be respawned by the scheduler".to_string());
+    }
+
+    let ready: Vec<DbResourceNode> = ctx
+        .db
+        .resource_node()
+        .iter()
+        .filter(|node| {
+            node.respawn_at
+                .is_some_and(|respawn_at| respawn_at <= ctx.timestamp)
+        })
+        .collect();
+    for mut node in ready {
+        node.remaining = node.capacity;
+        node.respawn_at = None;
+        ctx.db.resource_node().node_id().update(node);
+    }
+    Ok(())
+}
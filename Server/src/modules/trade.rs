@@ -0,0 +1,316 @@
+use crate::modules::inventory::{
+    inventory_can_afford, inventory_give_many, inventory_take_many, InventoryError, ItemRef,
+};
+use crate::modules::player::player;
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+
+// How close both players have to stand to open and complete a trade
+const TRADE_RANGE: f32 = 5.0;
+// Distinct items a player can put on their side of a trade
+const TRADE_OFFER_LIMIT: usize = 12;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DbTradeStatus {
+    Requested,
+    Open,
+}
+
+// Offered items stay in the owner's inventory until the trade goes through
+#[derive(SpacetimeType, Clone, Debug, Default)]
+pub struct DbTradeOffer {
+    pub items: Vec<ItemRef>,
+    pub locked: bool,
+    pub confirmed: bool,
+}
+
+// A trade session between two players, both sides lock their offer and
+// then confirm before the items are swapped
+#[spacetimedb::table(name = trade, public)]
+pub struct DbTrade {
+    #[primary_key]
+    #[auto_inc]
+    pub trade_id: u64,
+    #[index(btree)]
+    pub initiator: Identity,
+    #[index(btree)]
+    pub partner: Identity,
+    pub status: DbTradeStatus,
+    pub initiator_offer: DbTradeOffer,
+    pub partner_offer: DbTradeOffer,
+    pub created_at: Timestamp,
+}
+
+impl DbTrade {
+    fn offer_mut(&mut self, identity: Identity) -> &mut DbTradeOffer {
+        if identity == self.initiator {
+            &mut self.initiator_offer
+        } else {
+            &mut self.partner_offer
+        }
+    }
+
+    // Any change to the offers has to be looked at again by both players
+    fn reset_confirmation(&mut self) {
+        for offer in [&mut self.initiator_offer, &mut self.partner_offer] {
+            offer.locked = false;
+            offer.confirmed = false;
+        }
+    }
+}
+
+fn trade_find_for(ctx: &ReducerContext, identity: Identity) -> Option<DbTrade> {
+    ctx.db
+        .trade()
+        .initiator()
+        .filter(identity)
+        .chain(ctx.db.trade().partner().filter(identity))
+        .next()
+}
+
+// Cancels whatever trade the player is part of, for when they go offline
+pub fn trade_cancel_for(ctx: &ReducerContext, identity: Identity) {
+    if let Some(trade) = trade_find_for(ctx, identity) {
+        ctx.db.trade().trade_id().delete(trade.trade_id);
+    }
+}
+
+// Confirmations only hold for the items as they were, renaming, repairing
+// or moving an offered instance makes both players look again
+pub fn trade_instance_changed(ctx: &ReducerContext, identity: Identity, instance_id: Option<u64>) {
+    let Some(instance_id) = instance_id else {
+        return;
+    };
+    if let Some(mut trade) = trade_find_for(ctx, identity) {
+        let offered = trade
+            .offer_mut(identity)
+            .items
+            .iter()
+            .any(|item| item.instance_id == Some(instance_id));
+        if offered {
+            trade.reset_confirmation();
+            ctx.db.trade().trade_id().update(trade);
+        }
+    }
+}
+
+fn trade_require_nearby(
+    ctx: &ReducerContext,
+    first: Identity,
+    second: Identity,
+) -> Result<(), String> {
+    let first = ctx
+        .db
+        .player()
+        .identity()
+        .find(first)
+        .ok_or("Player not found")?;
+    let second = ctx
+        .db
+        .player()
+        .identity()
+        .find(second)
+        .ok_or("Player not found")?;
+    if !first.online || !second.online {
+        return Err("Both players have to be online to trade".to_string());
+    }
+    let distance = first.position.distance(&second.position);
+    if distance.is_nan() || distance > TRADE_RANGE {
+        return Err("Too far away to trade".to_string());
+    }
+    Ok(())
+}
+
+fn trade_get(ctx: &ReducerContext, trade_id: u64) -> Result<DbTrade, String> {
+    let trade = ctx
+        .db
+        .trade()
+        .trade_id()
+        .find(trade_id)
+        .ok_or("Trade not found")?;
+    if trade.initiator != ctx.sender && trade.partner != ctx.sender {
+        return Err("Not part of this trade".to_string());
+    }
+    Ok(trade)
+}
+
+// Open trade where the sender can still change their offer
+fn trade_get_editable(ctx: &ReducerContext, trade_id: u64) -> Result<DbTrade, String> {
+    let mut trade = trade_get(ctx, trade_id)?;
+    if trade.status != DbTradeStatus::Open {
+        return Err("Trade has not been accepted yet".to_string());
+    }
+    if trade.offer_mut(ctx.sender).locked {
+        return Err("Offer is locked".to_string());
+    }
+    Ok(trade)
+}
+
+#[spacetimedb::reducer]
+pub fn trade_request(ctx: &ReducerContext, partner: Identity) -> Result<(), String> {
+    if partner == ctx.sender {
+        return Err("Can't trade with yourself".to_string());
+    }
+    trade_require_nearby(ctx, ctx.sender, partner)?;
+    if trade_find_for(ctx, ctx.sender).is_some() {
+        return Err("Already in a trade".to_string());
+    }
+    if trade_find_for(ctx, partner).is_some() {
+        return Err("Player is already trading".to_string());
+    }
+
+    ctx.db.trade().insert(DbTrade {
+        trade_id: 0,
+        initiator: ctx.sender,
+        partner,
+        status: DbTradeStatus::Requested,
+        initiator_offer: DbTradeOffer::default(),
+        partner_offer: DbTradeOffer::default(),
+        created_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn trade_accept(ctx: &ReducerContext, trade_id: u64) -> Result<(), String> {
+    let mut trade = trade_get(ctx, trade_id)?;
+    if trade.partner != ctx.sender {
+        return Err("Only the invited player can accept".to_string());
+    }
+    if trade.status != DbTradeStatus::Requested {
+        return Err("Trade was already accepted".to_string());
+    }
+    trade_require_nearby(ctx, trade.initiator, trade.partner)?;
+
+    trade.status = DbTradeStatus::Open;
+    ctx.db.trade().trade_id().update(trade);
+    Ok(())
+}
+
+// Declines a request or walks away from an open trade
+#[spacetimedb::reducer]
+pub fn trade_cancel(ctx: &ReducerContext, trade_id: u64) -> Result<(), String> {
+    trade_get(ctx, trade_id)?;
+    ctx.db.trade().trade_id().delete(trade_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn trade_offer_item(
+    ctx: &ReducerContext,
+    trade_id: u64,
+    item_id: u32,
+    instance_id: Option<u64>,
+    quantity: u32,
+) -> Result<(), String> {
+    if quantity == 0 {
+        return Err(InventoryError::InvalidQuantity.into());
+    }
+    let mut trade = trade_get_editable(ctx, trade_id)?;
+
+    let offer = trade.offer_mut(ctx.sender);
+    match offer
+        .items
+        .iter_mut()
+        .find(|item| item.id == item_id && item.instance_id == instance_id)
+    {
+        Some(item) => {
+            item.quantity = item
+                .quantity
+                .checked_add(quantity)
+                .ok_or(InventoryError::QuantityOverflow)?;
+        }
+        None => {
+            if offer.items.len() >= TRADE_OFFER_LIMIT {
+                return Err("Offer is full".to_string());
+            }
+            offer.items.push(ItemRef {
+                id: item_id,
+                quantity,
+                instance_id,
+            });
+        }
+    }
+    // Only offer what is actually held, it's checked again on the swap
+    inventory_can_afford(ctx, ctx.sender, &offer.items)?;
+
+    trade.reset_confirmation();
+    ctx.db.trade().trade_id().update(trade);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn trade_remove_item(
+    ctx: &ReducerContext,
+    trade_id: u64,
+    item_id: u32,
+    instance_id: Option<u64>,
+    quantity: u32,
+) -> Result<(), String> {
+    if quantity == 0 {
+        return Err(InventoryError::InvalidQuantity.into());
+    }
+    let mut trade = trade_get_editable(ctx, trade_id)?;
+
+    let offer = trade.offer_mut(ctx.sender);
+    let index = offer
+        .items
+        .iter()
+        .position(|item| item.id == item_id && item.instance_id == instance_id)
+        .ok_or("Item is not part of the offer")?;
+    if offer.items[index].quantity <= quantity {
+        offer.items.remove(index);
+    } else {
+        offer.items[index].quantity -= quantity;
+    }
+
+    trade.reset_confirmation();
+    ctx.db.trade().trade_id().update(trade);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn trade_lock(ctx: &ReducerContext, trade_id: u64) -> Result<(), String> {
+    let mut trade = trade_get_editable(ctx, trade_id)?;
+    trade.offer_mut(ctx.sender).locked = true;
+    ctx.db.trade().trade_id().update(trade);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn trade_unlock(ctx: &ReducerContext, trade_id: u64) -> Result<(), String> {
+    let mut trade = trade_get(ctx, trade_id)?;
+    if !trade.offer_mut(ctx.sender).locked {
+        return Err("Offer is not locked".to_string());
+    }
+    trade.reset_confirmation();
+    ctx.db.trade().trade_id().update(trade);
+    Ok(())
+}
+
+// Once both sides confirmed the items are swapped in one go, if either
+// player no longer holds their offer nothing changes hands
+#[spacetimedb::reducer]
+pub fn trade_confirm(ctx: &ReducerContext, trade_id: u64) -> Result<(), String> {
+    let mut trade = trade_get(ctx, trade_id)?;
+    if !trade.initiator_offer.locked || !trade.partner_offer.locked {
+        return Err("Both offers have to be locked first".to_string());
+    }
+    trade.offer_mut(ctx.sender).confirmed = true;
+    if !trade.initiator_offer.confirmed || !trade.partner_offer.confirmed {
+        ctx.db.trade().trade_id().update(trade);
+        return Ok(());
+    }
+
+    if trade.initiator_offer.items.is_empty() && trade.partner_offer.items.is_empty() {
+        return Err("Nothing to trade".to_string());
+    }
+    trade_require_nearby(ctx, trade.initiator, trade.partner)?;
+    // Hand over exactly what was taken so item instances change hands too
+    let from_initiator = inventory_take_many(ctx, trade.initiator, &trade.initiator_offer.items)?;
+    let from_partner = inventory_take_many(ctx, trade.partner, &trade.partner_offer.items)?;
+    inventory_give_many(ctx, trade.initiator, &from_partner)?;
+    inventory_give_many(ctx, trade.partner, &from_initiator)?;
+
+    ctx.db.trade().trade_id().delete(trade_id);
+    Ok(())
+}
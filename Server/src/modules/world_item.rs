@@ -0,0 +1,152 @@
+use crate::modules::building_chunk::building_chunk_of;
+use crate::modules::inventory::{inventory_give_many, ItemRef};
+use crate::modules::item::item;
+use crate::modules::item_instance::item_instance_delete;
+use crate::modules::player::player_require_in_range;
+use crate::types::DbVector3;
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table, TimeDuration, Timestamp};
+use std::time::Duration;
+
+const DESPAWN_INTERVAL: Duration = Duration::from_secs(60);
+const WORLD_ITEM_LIFETIME: Duration = Duration::from_secs(10 * 60);
+// How long only the locked player can pick an item up
+const OWNER_LOCK_DURATION: Duration = Duration::from_secs(60);
+// How close a player has to stand to pick an item up
+const PICKUP_RANGE: f32 = 3.0;
+
+#[spacetimedb::table(
+    name = world_item,
+    public,
+    index(name = chunk, btree(columns = [chunk_x, chunk_z]))
+)]
+pub struct DbWorldItem {
+    #[primary_key]
+    #[auto_inc]
+    pub world_item_id: u64,
+    pub item_id: u32,
+    pub quantity: u32,
+    pub position: DbVector3,
+    pub chunk_x: i32,
+    pub chunk_z: i32,
+    pub spawned_at: Timestamp,
+    // Only this player can pick the item up until the lock runs out
+    pub locked_to: Option<Identity>,
+    pub instance_id: Option<u64>,
+}
+
+#[spacetimedb::table(name = world_item_despawn_schedule, scheduled(world_item_despawn_tick))]
+pub struct WorldItemDespawnSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+pub fn world_item_init(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db
+        .world_item_despawn_schedule()
+        .insert(WorldItemDespawnSchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(TimeDuration::from(DESPAWN_INTERVAL)),
+        });
+    Ok(())
+}
+
+// Puts items on the ground, split into stacks no bigger than the item allows
+pub fn world_item_spawn(
+    ctx: &ReducerContext,
+    item: &ItemRef,
+    position: &DbVector3,
+    locked_to: Option<Identity>,
+) {
+    let max_stack = ctx
+        .db
+        .item()
+        .id()
+        .find(item.id)
+        .map_or(item.quantity, |definition| definition.max_stack)
+        .max(1);
+    let (chunk_x, chunk_z) = building_chunk_of(position);
+
+    let mut remaining = item.quantity;
+    while remaining > 0 {
+        let stack = remaining.min(max_stack);
+        ctx.db.world_item().insert(DbWorldItem {
+            world_item_id: 0,
+            item_id: item.id,
+            quantity: stack,
+            position: position.clone(),
+            chunk_x,
+            chunk_z,
+            spawned_at: ctx.timestamp,
+            locked_to,
+            instance_id: item.instance_id,
+        });
+        remaining -= stack;
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn world_item_pickup(ctx: &ReducerContext, world_item_id: u64) -> Result<(), String> {
+    let world_item = ctx
+        .db
+        .world_item()
+        .world_item_id()
+        .find(world_item_id)
+        .ok_or("Item not found")?;
+    player_require_in_range(ctx, &world_item.position, PICKUP_RANGE)?;
+
+    let locked = world_item
+        .locked_to
+        .is_some_and(|identity| identity != ctx.sender)
+        && ctx
+            .timestamp
+            .duration_since(world_item.spawned_at)
+            .is_none_or(|elapsed| elapsed < OWNER_LOCK_DURATION);
+    if locked {
+        return Err("This item belongs to someone else".to_string());
+    }
+
+    inventory_give_many(
+        ctx,
+        ctx.sender,
+        &[ItemRef {
+            id: world_item.item_id,
+            quantity: world_item.quantity,
+            instance_id: world_item.instance_id,
+        }],
+    )?;
+    ctx.db.world_item().world_item_id().delete(world_item_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn world_item_despawn_tick(
+    ctx: &ReducerContext,
+    _schedule: WorldItemDespawnSchedule,
+) -> Result<(), String> {
+    if ctx.sender != ctx.identity() {
+        return Err("World items can only be despawned by the scheduler".to_string());
+    }
+
+    let expired: Vec<DbWorldItem> = ctx
+        .db
+        .world_item()
+        .iter()
+        .filter(|world_item| {
+            ctx.timestamp
+                .duration_since(world_item.spawned_at)
+                .is_some_and(|elapsed| elapsed > WORLD_ITEM_LIFETIME)
+        })
+        .collect();
+    for world_item in expired {
+        if let Some(instance_id) = world_item.instance_id {
+            item_instance_delete(ctx, instance_id);
+        }
+        ctx.db
+            .world_item()
+            .world_item_id()
+            .delete(world_item.world_item_id);
+    }
+    Ok(())
+}
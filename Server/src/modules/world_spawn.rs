@@ -30,7 +30,7 @@ pub fn world_spawn_set(
     position: DbVector3,
     rotation: DbVector3,
 ) -> Result<(), String> {
-    if let Some(mut spawn) = ctx.db.world_spawn().id().find(&id) {
+    if let Some(mut spawn) = ctx.db.world_spawn().id().find(id) {
         spawn.position = position;
         spawn.rotation = rotation;
         ctx.db.world_spawn().id().update(spawn);
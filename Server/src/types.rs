@@ -1,7 +1,8 @@
 // External crate imports
+use serde::Deserialize;
 use spacetimedb::SpacetimeType;
 
-#[derive(SpacetimeType, Clone, Debug)]
+#[derive(SpacetimeType, Deserialize, Clone, Debug)]
 pub struct DbVector3 {
     pub x: f32,
     pub y: f32,
@@ -18,7 +19,27 @@ impl Default for DbVector3 {
     }
 }
 
-#[derive(SpacetimeType, Clone, Debug)]
+impl DbVector3 {
+    pub fn distance(&self, other: &DbVector3) -> f32 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+
+    // Distance on the x/z plane, ignoring height
+    pub fn horizontal_distance(&self, other: &DbVector3) -> f32 {
+        let dx = self.x - other.x;
+        let dz = self.z - other.z;
+        (dx * dx + dz * dz).sqrt()
+    }
+
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+}
+
+#[derive(SpacetimeType, Deserialize, Clone, Debug)]
 pub struct DbVector2 {
     pub x: f32,
     pub y: f32,